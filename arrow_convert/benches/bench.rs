@@ -5,7 +5,9 @@ use arrow::{
 use arrow_convert::{
     deserialize::TryIntoCollection, serialize::TryIntoArrow, ArrowDeserialize, ArrowField, ArrowSerialize,
 };
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 // Arrow stores U8 arrays as `arrow::array::BinaryArray`
 #[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
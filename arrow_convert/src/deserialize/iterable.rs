@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
-use super::{BufferBinaryArray, BufferBinaryArrayIter};
+use super::{
+    BufferBinaryArray, BufferBinaryArrayIter, BufferSliceBinaryArray, BufferSliceBinaryArrayIter,
+    PrimitiveSliceListArray, PrimitiveSliceListArrayIter,
+};
 use arrow_array::{iterator::*, ArrowPrimitiveType, *};
+use arrow_buffer::Buffer;
 
 /// A trait for Arrow arrays that can be transformed into an iterator.
 pub trait ArrowArrayIterable {
@@ -59,6 +63,26 @@ impl ArrowArrayIterable for BufferBinaryArray {
     }
 }
 
+impl ArrowArrayIterable for BufferSliceBinaryArray {
+    type Item<'a> = Option<Buffer>;
+
+    type Iter<'a> = BufferSliceBinaryArrayIter<'a>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<T: ArrowPrimitiveType> ArrowArrayIterable for PrimitiveSliceListArray<T> {
+    type Item<'a> = Option<&'a [T::Native]>;
+
+    type Iter<'a> = PrimitiveSliceListArrayIter<'a, T>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait> ArrowArrayIterable for GenericBinaryArray<OffsetSize> {
     type Item<'a> = Option<&'a [u8]>;
 
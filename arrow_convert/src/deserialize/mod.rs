@@ -2,11 +2,33 @@
 mod iterable;
 pub use iterable::*;
 
+mod tuple;
+pub use tuple::*;
+
+mod typed_array;
+pub use typed_array::*;
+
+mod projection;
+pub use projection::*;
+
+mod row_view;
+pub use row_view::*;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+
 use arrow_array::{types, ArrowPrimitiveType, *};
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
 
 use crate::field::*;
+use crate::json::parse_json_number_array;
 
 /// Implemented by [`ArrowField`] that can be deserialized from arrow
 pub trait ArrowDeserialize: ArrowField + Sized
@@ -19,6 +41,17 @@ where
     /// Deserialize this field from arrow
     fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type>;
 
+    /// Validates the values of an array before it is deserialized.
+    ///
+    /// Called by the top-level deserialization entry points once the data type of the array has been
+    /// checked. Types whose physical arrow representation admits values that cannot be converted, such as
+    /// non-ASCII bytes for `TinyAsciiStr`, override this to report a descriptive error instead of panicking
+    /// during iteration. Nested types forward to the validation of their children.
+    #[inline]
+    fn validate_array(_arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        Ok(())
+    }
+
     #[inline]
     #[doc(hidden)]
     /// For internal use only
@@ -32,6 +65,37 @@ where
     }
 }
 
+/// Implemented by [`ArrowField`] that can be deserialized by borrowing from an arrow array.
+///
+/// This is the zero-copy counterpart of [`ArrowDeserialize`] for types such as `&'a str`, `&'a [u8]`
+/// and `&'a [T]` where `T` is a primitive type. The deserialized values are tied to the lifetime `'a`
+/// of the source array.
+///
+/// The derive macro generates implementations for structs that have a lifetime parameter.
+pub trait ArrowDeserializeBorrowed<'a>: ArrowField + Sized
+where
+    Self::ArrayType: ArrowArray,
+{
+    /// The `arrow::Array` type corresponding to this field
+    type ArrayType: 'static;
+
+    /// Deserialize this field from arrow by borrowing from the array
+    fn arrow_deserialize_borrowed(
+        v: <Self::ArrayType as ArrowArrayIterable>::Item<'a>,
+    ) -> Option<<Self as ArrowField>::Type>;
+
+    #[inline]
+    #[doc(hidden)]
+    /// For internal use only
+    ///
+    /// See [`ArrowDeserialize::arrow_deserialize_internal`].
+    fn arrow_deserialize_borrowed_internal(
+        v: <Self::ArrayType as ArrowArrayIterable>::Item<'a>,
+    ) -> <Self as ArrowField>::Type {
+        Self::arrow_deserialize_borrowed(v).unwrap()
+    }
+}
+
 /// Internal trait used to support deserialization and iteration of structs, and nested struct lists
 ///
 /// Trivial pass-thru implementations are provided for arrow arrays that implement ArrowArrayIterable.
@@ -46,6 +110,16 @@ where
 
     // Returns a typed iterator to the underlying elements of the array from an untyped Array reference.
     fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_>;
+
+    // Returns the element at `index` from an untyped Array reference. Panics if `index` is out of bounds.
+    //
+    // The default implementation walks the iterator, implementations should override it with O(1) access.
+    #[inline]
+    fn value_from_array_ref(b: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+        Self::iter_from_array_ref(b)
+            .nth(index)
+            .unwrap_or_else(|| panic!("index {index} out of bounds for array of length {}", b.len()))
+    }
 }
 
 // Macro to facilitate implementation for numeric types and numeric arrays.
@@ -74,6 +148,12 @@ macro_rules! impl_arrow_array {
                 let b = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
                 <Self as ArrowArrayIterable>::iter(b)
             }
+
+            #[inline]
+            fn value_from_array_ref(b: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+                let b = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+                b.is_valid(index).then(|| b.value(index))
+            }
         }
     };
 }
@@ -96,6 +176,34 @@ where
     fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> <Self as ArrowField>::Type {
         <T as ArrowDeserialize>::arrow_deserialize(v)
     }
+
+    #[inline]
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        <T as ArrowDeserialize>::validate_array(arr)
+    }
+}
+
+// blanket implementation for optional borrowed fields
+impl<'a, T> ArrowDeserializeBorrowed<'a> for Option<T>
+where
+    T: ArrowDeserializeBorrowed<'a>,
+    T::ArrayType: ArrowArray,
+{
+    type ArrayType = <T as ArrowDeserializeBorrowed<'a>>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize_borrowed(
+        v: <Self::ArrayType as ArrowArrayIterable>::Item<'a>,
+    ) -> Option<<Self as ArrowField>::Type> {
+        Self::arrow_deserialize_borrowed_internal(v).map(Some)
+    }
+
+    #[inline]
+    fn arrow_deserialize_borrowed_internal(
+        v: <Self::ArrayType as ArrowArrayIterable>::Item<'a>,
+    ) -> <Self as ArrowField>::Type {
+        <T as ArrowDeserializeBorrowed<'a>>::arrow_deserialize_borrowed(v)
+    }
 }
 
 impl_arrow_deserialize_primitive!(u8, types::UInt8Type);
@@ -130,6 +238,15 @@ impl ArrowDeserialize for String {
     }
 }
 
+impl<'a> ArrowDeserializeBorrowed<'a> for &'a str {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize_borrowed(v: Option<&'a str>) -> Option<Self> {
+        v
+    }
+}
+
 impl ArrowDeserialize for LargeString {
     type ArrayType = LargeStringArray;
 
@@ -175,6 +292,42 @@ impl ArrowDeserialize for NaiveDate {
     }
 }
 
+impl<T> ArrowDeserialize for ClampTemporal<T>
+where
+    T: ArrowDeserialize + ArrowField<Type = T>,
+    T::ArrayType: ArrowArray,
+{
+    type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<T> {
+        <T as ArrowDeserialize>::arrow_deserialize(v)
+    }
+
+    #[inline]
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        <T as ArrowDeserialize>::validate_array(arr)
+    }
+}
+
+impl<T> ArrowDeserialize for NullTemporal<T>
+where
+    T: ArrowDeserialize + ArrowField<Type = T>,
+    T::ArrayType: ArrowArray,
+{
+    type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<Option<T>> {
+        Self::arrow_deserialize_internal(v).map(Some)
+    }
+
+    #[inline]
+    fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<T> {
+        <T as ArrowDeserialize>::arrow_deserialize(v)
+    }
+}
+
 /// Iterator for for [`BufferBinaryArray`]
 pub struct BufferBinaryArrayIter<'a> {
     index: usize,
@@ -210,23 +363,141 @@ impl ArrowArray for BufferBinaryArray {
 
         BufferBinaryArrayIter { index: 0, array: b }
     }
+
+    #[inline]
+    fn value_from_array_ref(a: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        b.is_valid(index).then(|| b.value(index))
+    }
+}
+
+/// Iterator for [`BufferSliceBinaryArray`]
+pub struct BufferSliceBinaryArrayIter<'a> {
+    index: usize,
+    array: &'a BinaryArray,
+}
+
+impl BufferSliceBinaryArrayIter<'_> {
+    // Slices the value at `index` out of the value buffer of the array, sharing its memory.
+    #[inline]
+    fn buffer(&self, index: usize) -> Buffer {
+        let offsets = self.array.value_offsets();
+        let start = offsets[index].as_usize();
+        let end = offsets[index + 1].as_usize();
+        self.array.values().slice_with_length(start, end - start)
+    }
+}
+
+impl Iterator for BufferSliceBinaryArrayIter<'_> {
+    type Item = Option<Buffer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            None
+        } else if self.array.is_valid(self.index) {
+            let value = self.buffer(self.index);
+            self.index += 1;
+            Some(Some(value))
+        } else {
+            self.index += 1;
+            Some(None)
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over a `BinaryArray` while exposing zero-copy [`Buffer`] slices of its
+/// values, unlike [`BufferBinaryArray`] which exposes borrowed byte slices
+pub struct BufferSliceBinaryArray;
+
+impl ArrowArray for BufferSliceBinaryArray {
+    type BaseArrayType = BinaryArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+
+        BufferSliceBinaryArrayIter { index: 0, array: b }
+    }
+
+    #[inline]
+    fn value_from_array_ref(a: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        b.is_valid(index)
+            .then(|| BufferSliceBinaryArrayIter { index, array: b }.buffer(index))
+    }
+}
+
+/// Iterator for [`PrimitiveSliceListArray`]
+pub struct PrimitiveSliceListArrayIter<'a, T: ArrowPrimitiveType> {
+    index: usize,
+    array: &'a ListArray,
+    values: &'a [T::Native],
+}
+
+impl<'a, T: ArrowPrimitiveType> Iterator for PrimitiveSliceListArrayIter<'a, T> {
+    type Item = Option<&'a [T::Native]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            None
+        } else if self.array.is_valid(self.index) {
+            let offsets = self.array.value_offsets();
+            let start = offsets[self.index].as_usize();
+            let end = offsets[self.index + 1].as_usize();
+            self.index += 1;
+            Some(Some(&self.values[start..end]))
+        } else {
+            self.index += 1;
+            Some(None)
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over a `ListArray` of primitives while exposing borrowed slices
+pub struct PrimitiveSliceListArray<T: ArrowPrimitiveType>(std::marker::PhantomData<T>);
+
+impl<T: ArrowPrimitiveType> ArrowArray for PrimitiveSliceListArray<T> {
+    type BaseArrayType = ListArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        let values = b
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap()
+            .values();
+
+        PrimitiveSliceListArrayIter {
+            index: 0,
+            array: b,
+            values,
+        }
+    }
+
+    #[inline]
+    fn value_from_array_ref(a: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+        let mut iter = Self::iter_from_array_ref(a);
+        iter.index = index;
+        iter.next()
+            .unwrap_or_else(|| panic!("index {index} out of bounds for array of length {}", a.len()))
+    }
 }
 
-// Treat both Buffer and ScalarBuffer<u8> the same
+// Treat both Buffer and ScalarBuffer<u8> the same, both share the value buffer of the array without copying
 impl ArrowDeserialize for Buffer {
-    type ArrayType = BufferBinaryArray;
+    type ArrayType = BufferSliceBinaryArray;
 
     #[inline]
-    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
-        v.map(|t| t.into())
+    fn arrow_deserialize(v: Option<Buffer>) -> Option<Self> {
+        v
     }
 }
 impl ArrowDeserialize for ScalarBuffer<u8> {
-    type ArrayType = BufferBinaryArray;
+    type ArrayType = BufferSliceBinaryArray;
 
     #[inline]
-    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
-        v.map(|t| ScalarBuffer::from(t.to_vec()))
+    fn arrow_deserialize(v: Option<Buffer>) -> Option<Self> {
+        v.map(ScalarBuffer::from)
     }
 }
 
@@ -239,6 +510,15 @@ impl ArrowDeserialize for Vec<u8> {
     }
 }
 
+impl<'a> ArrowDeserializeBorrowed<'a> for &'a [u8] {
+    type ArrayType = BinaryArray;
+
+    #[inline]
+    fn arrow_deserialize_borrowed(v: Option<&'a [u8]>) -> Option<Self> {
+        v
+    }
+}
+
 impl ArrowDeserialize for LargeBinary {
     type ArrayType = LargeBinaryArray;
 
@@ -266,16 +546,76 @@ impl<const SIZE: usize> ArrowDeserialize for [u8; SIZE] {
     }
 }
 
+impl ArrowDeserialize for Ipv4Addr {
+    type ArrayType = UInt32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<u32>) -> Option<Self> {
+        v.map(Ipv4Addr::from)
+    }
+}
+
+impl ArrowDeserialize for Ipv4Binary {
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Ipv4Addr> {
+        v.and_then(|t| <[u8; 4]>::try_from(t).ok()).map(Ipv4Addr::from)
+    }
+}
+
+impl ArrowDeserialize for Ipv6Addr {
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.and_then(|t| <[u8; 16]>::try_from(t).ok()).map(Ipv6Addr::from)
+    }
+}
+
+impl ArrowDeserialize for IpAddr {
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        <Ipv6Addr as ArrowDeserialize>::arrow_deserialize(v).map(|v6| v6.to_canonical())
+    }
+}
+
+impl ArrowDeserialize for SocketAddr {
+    type ArrayType = Tuple2Array<IpAddr, u16>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<(IpAddr, u16)>) -> Option<Self> {
+        v.map(|(ip, port)| SocketAddr::new(ip, port))
+    }
+}
+
+impl ArrowDeserialize for PathBuf {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(PathBuf::from)
+    }
+}
+
 pub(crate) fn arrow_deserialize_vec_helper<T>(v: Option<ArrayRef>) -> Option<<Vec<T> as ArrowField>::Type>
 where
     T: ArrowDeserialize + ArrowEnableVecForType + 'static,
     T::ArrayType: ArrowArrayIterable,
+{
+    arrow_deserialize_collection_helper::<T, _>(v)
+}
+
+pub(crate) fn arrow_deserialize_collection_helper<T, C>(v: Option<ArrayRef>) -> Option<C>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    T::ArrayType: ArrowArrayIterable,
+    C: FromIterator<<T as ArrowField>::Type>,
 {
     use std::ops::Deref;
-    v.map(|t| {
-        arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(t.deref())
-            .collect::<Vec<<T as ArrowField>::Type>>()
-    })
+    v.map(|t| arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(t.deref()).collect::<C>())
 }
 
 // Blanket implementation for ScalarBuffer
@@ -295,6 +635,20 @@ where
     }
 }
 
+// Blanket implementation for borrowed slices of primitives
+impl<'a, T, K> ArrowDeserializeBorrowed<'a> for &'a [T]
+where
+    K: ArrowPrimitiveType<Native = T>,
+    T: ArrowDeserialize<ArrayType = PrimitiveArray<K>> + ArrowNativeType + ArrowEnableVecForType,
+{
+    type ArrayType = PrimitiveSliceListArray<K>;
+
+    #[inline]
+    fn arrow_deserialize_borrowed(v: Option<&'a [T]>) -> Option<Self> {
+        v
+    }
+}
+
 // Blanket implementation for Vec
 impl<T> ArrowDeserialize for Vec<T>
 where
@@ -307,6 +661,65 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
+}
+
+// Blanket implementations for other collections, reusing the Vec list deserialization
+impl<T> ArrowDeserialize for VecDeque<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_collection_helper::<T, _>(v)
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
+}
+
+impl<T> ArrowDeserialize for BTreeSet<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowField>::Type: Ord,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_collection_helper::<T, _>(v)
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
+}
+
+impl<T, S> ArrowDeserialize for HashSet<T, S>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowField>::Type: Eq + Hash,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    S: BuildHasher + Default,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_collection_helper::<T, _>(v)
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
 }
 
 impl<T> ArrowDeserialize for LargeVec<T>
@@ -320,6 +733,10 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
 }
 
 impl<T, const SIZE: i32> ArrowDeserialize for FixedSizeVec<T, SIZE>
@@ -333,6 +750,10 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
 }
 impl<T, const SIZE: usize> ArrowDeserialize for [T; SIZE]
 where
@@ -356,6 +777,30 @@ where
             array => array,
         }
     }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
+}
+
+impl<T, S> ArrowDeserialize for Tensor<T, S>
+where
+    T: TensorData + 'static,
+    T::Element: ArrowDeserialize + 'static,
+    <T::Element as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    S: TensorShape + 'static,
+{
+    type ArrayType = FixedSizeListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<T> {
+        let v = v?;
+        let mut elements = arrow_array_deserialize_iterator_internal::<T::Element, T::Element>(v.as_ref());
+        T::from_tensor_elements(&S::shape(), &mut elements)
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T::Element>(arr)
+    }
 }
 
 impl_arrow_array!(BooleanArray);
@@ -373,6 +818,14 @@ impl_arrow_array!(TimestampSecondArray);
 impl_arrow_array!(TimestampMillisecondArray);
 impl_arrow_array!(TimestampMicrosecondArray);
 impl_arrow_array!(TimestampNanosecondArray);
+impl_arrow_array!(Time32SecondArray);
+impl_arrow_array!(Time32MillisecondArray);
+impl_arrow_array!(Time64MicrosecondArray);
+impl_arrow_array!(Time64NanosecondArray);
+impl_arrow_array!(DurationSecondArray);
+impl_arrow_array!(DurationMillisecondArray);
+impl_arrow_array!(DurationMicrosecondArray);
+impl_arrow_array!(DurationNanosecondArray);
 
 /// Top-level API to deserialize from Arrow
 pub trait TryIntoCollection<Collection, Element>
@@ -402,6 +855,141 @@ where
         .map(<Field as ArrowDeserialize>::arrow_deserialize_internal)
 }
 
+// Checks that the data type of `arr` matches the data type of `T`.
+pub(crate) fn check_data_type<T: ArrowField>(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+    if <T as ArrowField>::data_type() != *arr.data_type() {
+        Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+            "Data type mismatch. Expected type={:#?} is_nullable={}, but was type={:#?} is_nullable={}",
+            &<T as ArrowField>::data_type(),
+            &<T as ArrowField>::is_nullable(),
+            arr.data_type(),
+            arr.is_nullable()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+// Checks that every non-null value of the primitive array `arr` deserializes to `T`, reporting the first value that
+// is out of the range of `T`, such as a day number beyond the last representable date.
+#[cfg(any(feature = "time", feature = "jiff"))]
+pub(crate) fn validate_temporal_values<T, P>(arr: &dyn Array, type_name: &str) -> Result<(), arrow_schema::ArrowError>
+where
+    P: ArrowPrimitiveType,
+    T: ArrowDeserialize<ArrayType = PrimitiveArray<P>>,
+{
+    let Some(arr) = arr.as_any().downcast_ref::<PrimitiveArray<P>>() else {
+        return Ok(());
+    };
+    for (index, value) in arr.iter().enumerate() {
+        let Some(value) = value else { continue };
+        if <T as ArrowDeserialize>::arrow_deserialize(Some(value)).is_none() {
+            return Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                "invalid {type_name} at index {index}: {value:?} is out of range"
+            )));
+        }
+    }
+    Ok(())
+}
+
+// Validates the child values referenced by the slots of a list-like array with `T`.
+pub(crate) fn validate_list_values<T: ArrowDeserialize>(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+    use arrow_array::cast::AsArray;
+
+    if arr.is_empty() {
+        return Ok(());
+    }
+    let values = match arr.data_type() {
+        arrow_schema::DataType::List(_) => {
+            let arr = arr.as_list::<i32>();
+            let offsets = arr.value_offsets();
+            let start = offsets[0] as usize;
+            arr.values().slice(start, offsets[arr.len()] as usize - start)
+        }
+        arrow_schema::DataType::LargeList(_) => {
+            let arr = arr.as_list::<i64>();
+            let offsets = arr.value_offsets();
+            let start = offsets[0] as usize;
+            arr.values().slice(start, offsets[arr.len()] as usize - start)
+        }
+        arrow_schema::DataType::FixedSizeList(_, size) => {
+            let arr = arr.as_fixed_size_list();
+            arr.values()
+                .slice(arr.value_offset(0) as usize, arr.len() * *size as usize)
+        }
+        _ => return Ok(()),
+    };
+    <T as ArrowDeserialize>::validate_array(values.as_ref())
+}
+
+/// Checks that the extension name of `field`, if present, matches the extension name of `T`.
+///
+/// Fields without an extension name are accepted, so that columns written by producers that don't attach extension
+/// metadata can still be read. [`arrow_array_project`] and [`record_batch_project`] apply this check to every
+/// projected child field, and [`FieldArray`] applies it when deserializing an array together with its field.
+pub fn check_extension_type<T: ArrowField>(field: &arrow_schema::Field) -> Result<(), arrow_schema::ArrowError> {
+    check_field_extension(&<T as ArrowField>::field(field.name()), field, field.name())
+}
+
+// Checks that the extension name of `actual`, if present, matches the one of `expected`, as well as the shape and
+// permutation of fixed shape tensors. `path` names the field in errors.
+pub(crate) fn check_field_extension(
+    expected: &arrow_schema::Field,
+    actual: &arrow_schema::Field,
+    path: &str,
+) -> Result<(), arrow_schema::ArrowError> {
+    match actual.extension_type_name() {
+        Some(name) if expected.extension_type_name() != Some(name) => {
+            Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                "Extension type mismatch for field '{path}'. Expected extension={:?}, but was extension={name:?}",
+                expected.extension_type_name()
+            )))
+        }
+        Some(name) if name == <Tensor<f32> as ArrowExtension>::NAME => check_tensor_layout(expected, actual, path),
+        _ => Ok(()),
+    }
+}
+
+// The storage type of a fixed shape tensor only records the number of elements, so tensors of the same size but of a
+// different shape or permutation are told apart by the extension metadata.
+fn check_tensor_layout(
+    expected: &arrow_schema::Field,
+    actual: &arrow_schema::Field,
+    path: &str,
+) -> Result<(), arrow_schema::ArrowError> {
+    let layout = |field: &arrow_schema::Field| -> Result<(Vec<usize>, Vec<usize>), String> {
+        let metadata = field.extension_type_metadata().ok_or("missing metadata")?;
+        let shape = parse_json_number_array(metadata, "shape")?.ok_or("missing shape")?;
+        let permutation =
+            parse_json_number_array(metadata, "permutation")?.unwrap_or_else(|| (0..shape.len()).collect());
+        Ok((shape, permutation))
+    };
+    let invalid = |e: String| {
+        arrow_schema::ArrowError::InvalidArgumentError(format!(
+            "Invalid {} metadata for field '{path}': {e}",
+            <Tensor<f32> as ArrowExtension>::NAME
+        ))
+    };
+    let (expected_shape, expected_permutation) = layout(expected).map_err(invalid)?;
+    let (shape, permutation) = layout(actual).map_err(invalid)?;
+    if (&expected_shape, &expected_permutation) != (&shape, &permutation) {
+        return Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+            "Tensor layout mismatch for field '{path}'. Expected shape={expected_shape:?} and \
+             permutation={expected_permutation:?}, but was shape={shape:?} and permutation={permutation:?}"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `arr` has the data type of `T` and that its values can be deserialized to `T`.
+///
+/// This is the check performed by the top-level deserialization entry points and [`TypedArray::try_new`] before any
+/// element is deserialized.
+pub fn check_array<T: ArrowDeserialize>(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+    check_data_type::<T>(arr)?;
+    <T as ArrowDeserialize>::validate_array(arr)
+}
+
 /// Returns a typed iterator to a target type from an `arrow::Array`
 pub fn arrow_array_deserialize_iterator_as_type<Element, ArrowType>(
     arr: &dyn Array,
@@ -411,19 +999,39 @@ where
     ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
     <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
 {
-    if &<ArrowType as ArrowField>::data_type() != arr.data_type() {
-        Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
-            "Data type mismatch. Expected type={:#?} is_nullable={}, but was type={:#?} is_nullable={}",
-            &<ArrowType as ArrowField>::data_type(),
-            &<ArrowType as ArrowField>::is_nullable(),
-            arr.data_type(),
-            arr.is_nullable()
-        )))
-    } else {
-        Ok(arrow_array_deserialize_iterator_internal::<Element, ArrowType>(
-            arr,
-        ))
-    }
+    check_array::<ArrowType>(arr)?;
+    Ok(arrow_array_deserialize_iterator_internal::<Element, ArrowType>(
+        arr,
+    ))
+}
+
+/// Same as [`arrow_array_deserialize_iterator_as_type`], but also checks the extension name of `field`, the field
+/// describing `arr` (for example a schema field of a [`RecordBatch`](arrow_array::RecordBatch) column), with
+/// [`check_extension_type`].
+pub fn arrow_field_deserialize_iterator_as_type<'a, Element, ArrowType>(
+    field: &arrow_schema::Field,
+    arr: &'a dyn Array,
+) -> Result<impl Iterator<Item = Element> + 'a, arrow_schema::ArrowError>
+where
+    Element: 'static,
+    ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+    <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    check_extension_type::<ArrowType>(field)?;
+    arrow_array_deserialize_iterator_as_type::<Element, ArrowType>(arr)
+}
+
+/// Same as [`arrow_array_deserialize_iterator`], but also checks the extension name of `field`, the field
+/// describing `arr`, with [`check_extension_type`].
+pub fn arrow_field_deserialize_iterator<'a, T>(
+    field: &arrow_schema::Field,
+    arr: &'a dyn Array,
+) -> Result<impl Iterator<Item = T> + 'a, arrow_schema::ArrowError>
+where
+    T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    arrow_field_deserialize_iterator_as_type::<T, T>(field, arr)
 }
 
 /// Return an iterator that deserializes an [`Array`] to an element of type T
@@ -437,6 +1045,25 @@ where
     arrow_array_deserialize_iterator_as_type::<T, T>(arr)
 }
 
+/// Return an iterator that deserializes an [`Array`] to an element of type T borrowing from the array.
+///
+/// Unlike [`arrow_array_deserialize_iterator`], the deserialized elements can hold references into the
+/// array's buffers, for example `&'a str` or derived structs with a lifetime parameter, avoiding a copy
+/// of every value.
+pub fn arrow_array_deserialize_iterator_borrowed<'a, T>(
+    arr: &'a dyn Array,
+) -> Result<impl Iterator<Item = T> + 'a, arrow_schema::ArrowError>
+where
+    T: ArrowDeserializeBorrowed<'a> + ArrowField<Type = T> + 'a,
+    <T as ArrowDeserializeBorrowed<'a>>::ArrayType: ArrowArray,
+{
+    check_data_type::<T>(arr)?;
+    Ok(
+        <<T as ArrowDeserializeBorrowed<'a>>::ArrayType as ArrowArray>::iter_from_array_ref(arr)
+            .map(<T as ArrowDeserializeBorrowed<'a>>::arrow_deserialize_borrowed_internal),
+    )
+}
+
 impl<Collection, Element, ArrowArray> TryIntoCollection<Collection, Element> for ArrowArray
 where
     Element: 'static,
@@ -459,3 +1086,72 @@ where
         Ok(arrow_array_deserialize_iterator_as_type::<Element, ArrowType>(self.borrow())?.collect())
     }
 }
+
+/// An array together with the [`Field`](arrow_schema::Field) describing it, for example a column of a
+/// [`RecordBatch`](arrow_array::RecordBatch) and its schema field.
+///
+/// Unlike an array alone, deserializing a `FieldArray` with [`TryIntoCollection`] also checks the extension name of
+/// the field against the target type with [`check_extension_type`].
+///
+/// ```rust
+/// # use arrow::array::{ArrayRef, RecordBatch};
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use arrow_convert::deserialize::{FieldArray, TryIntoCollection};
+/// # use std::sync::Arc;
+/// let column: ArrayRef = Arc::new(arrow::array::Int64Array::from(vec![1, 2]));
+/// let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+/// let batch = RecordBatch::try_new(Arc::new(schema), vec![column]).unwrap();
+/// let ids: Vec<i64> = FieldArray::from_record_batch(&batch, "id").unwrap().try_into_collection().unwrap();
+/// assert_eq!(ids, vec![1, 2]);
+/// ```
+pub struct FieldArray<'a, A> {
+    field: &'a arrow_schema::Field,
+    array: A,
+}
+
+impl<'a, A> FieldArray<'a, A>
+where
+    A: std::borrow::Borrow<dyn Array>,
+{
+    /// Pairs `array` with the field describing it.
+    pub fn new(field: &'a arrow_schema::Field, array: A) -> Self {
+        Self { field, array }
+    }
+}
+
+impl<'a> FieldArray<'a, ArrayRef> {
+    /// The column named `name` of `batch`, together with its schema field.
+    pub fn from_record_batch(
+        batch: &'a arrow_array::RecordBatch,
+        name: &str,
+    ) -> Result<Self, arrow_schema::ArrowError> {
+        let index = batch.schema_ref().index_of(name)?;
+        Ok(Self::new(
+            batch.schema_ref().field(index),
+            batch.column(index).clone(),
+        ))
+    }
+}
+
+impl<Collection, Element, A> TryIntoCollection<Collection, Element> for FieldArray<'_, A>
+where
+    Element: 'static,
+    A: std::borrow::Borrow<dyn Array>,
+    Collection: FromIterator<Element>,
+{
+    fn try_into_collection(self) -> Result<Collection, arrow_schema::ArrowError>
+    where
+        Element: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+        <Element as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    {
+        Ok(arrow_field_deserialize_iterator::<Element>(self.field, self.array.borrow())?.collect())
+    }
+
+    fn try_into_collection_as_type<ArrowType>(self) -> Result<Collection, arrow_schema::ArrowError>
+    where
+        ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+        <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    {
+        Ok(arrow_field_deserialize_iterator_as_type::<Element, ArrowType>(self.field, self.array.borrow())?.collect())
+    }
+}
@@ -0,0 +1,82 @@
+use std::borrow::Borrow;
+
+use arrow_array::Array;
+use arrow_schema::ArrowError;
+use rayon::prelude::*;
+
+use super::{arrow_array_deserialize_iterator_as_type, check_data_type, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::ArrowField;
+use crate::parallel::default_chunk_size;
+
+/// Deserializes an [`Array`] into a `Vec` in parallel.
+///
+/// The array is split into zero-copy slices of `chunk_size` elements, each slice is deserialized on the
+/// rayon thread pool, and the results are collected in order.
+pub fn arrow_array_deserialize_parallel<Element, ArrowType>(
+    arr: &dyn Array,
+    chunk_size: usize,
+) -> Result<Vec<Element>, ArrowError>
+where
+    Element: Send + 'static,
+    ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+    <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    check_data_type::<ArrowType>(arr)?;
+
+    let chunk_size = chunk_size.max(1);
+    let chunks = (0..arr.len())
+        .step_by(chunk_size)
+        .map(|offset| arr.slice(offset, chunk_size.min(arr.len() - offset)))
+        .collect::<Vec<_>>();
+
+    let chunks = chunks
+        .par_iter()
+        .map(|chunk| {
+            Ok(arrow_array_deserialize_iterator_as_type::<Element, ArrowType>(chunk.as_ref())?.collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let mut result = Vec::with_capacity(arr.len());
+    for chunk in chunks {
+        result.extend(chunk);
+    }
+    Ok(result)
+}
+
+/// Top-level API to deserialize from Arrow in parallel, see [`arrow_array_deserialize_parallel`].
+pub trait TryIntoCollectionParallel<Element>
+where
+    Element: Send + 'static,
+{
+    /// Convert from a `arrow::Array` to a `Vec` in parallel
+    fn try_into_vec_par(self) -> Result<Vec<Element>, ArrowError>
+    where
+        Element: ArrowDeserialize + ArrowField<Type = Element>;
+
+    /// Same as `try_into_vec_par` except can coerce the conversion to a specific Arrow type.
+    fn try_into_vec_par_as_type<ArrowType>(self) -> Result<Vec<Element>, ArrowError>
+    where
+        ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static;
+}
+
+impl<Element, ArrowArray> TryIntoCollectionParallel<Element> for ArrowArray
+where
+    Element: Send + 'static,
+    ArrowArray: Borrow<dyn Array>,
+{
+    fn try_into_vec_par(self) -> Result<Vec<Element>, ArrowError>
+    where
+        Element: ArrowDeserialize + ArrowField<Type = Element>,
+    {
+        let arr = self.borrow();
+        arrow_array_deserialize_parallel::<Element, Element>(arr, default_chunk_size(arr.len()))
+    }
+
+    fn try_into_vec_par_as_type<ArrowType>(self) -> Result<Vec<Element>, ArrowError>
+    where
+        ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+    {
+        let arr = self.borrow();
+        arrow_array_deserialize_parallel::<Element, ArrowType>(arr, default_chunk_size(arr.len()))
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    cast::AsArray, Array, ArrayRef, FixedSizeListArray, LargeListArray, ListArray, RecordBatch, StructArray,
+};
+use arrow_schema::{ArrowError, DataType};
+
+use super::check_field_extension;
+use crate::field::ArrowField;
+
+/// Projects `array` onto the data type of `T`, selecting struct children by field name.
+///
+/// `array` may be a superset of `T`: struct children that `T` doesn't declare are dropped, and the remaining
+/// children are reordered to match `T`. Projection recurses into nested structs and lists of structs. The
+/// child arrays are shared with `array`, so no values are copied.
+///
+/// Child fields take the field metadata declared by `T`. If a child field of `array` carries an extension name, it
+/// must match the extension name declared by `T`, see [`check_extension_type`](super::check_extension_type).
+///
+/// This allows deserializing a narrow struct from a wide array:
+///
+/// ```rust
+/// # use arrow::array::ArrayRef;
+/// # use arrow_convert::deserialize::{arrow_array_project, TryIntoCollection};
+/// # use arrow_convert::serialize::TryIntoArrow;
+/// # use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+/// #[derive(ArrowField, ArrowSerialize)]
+/// struct Order {
+///     id: u64,
+///     venue: String,
+///     px: f64,
+/// }
+///
+/// #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+/// struct OrderSummary {
+///     px: f64,
+///     id: u64,
+/// }
+///
+/// let orders: ArrayRef = vec![Order { id: 1, venue: "X".into(), px: 2.5 }].try_into_arrow().unwrap();
+/// let summaries: Vec<OrderSummary> = arrow_array_project::<OrderSummary>(orders.as_ref())
+///     .unwrap()
+///     .try_into_collection()
+///     .unwrap();
+/// assert_eq!(summaries, vec![OrderSummary { px: 2.5, id: 1 }]);
+/// ```
+pub fn arrow_array_project<T: ArrowField>(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    project(&<T as ArrowField>::data_type(), array, "")
+}
+
+/// Projects the columns of `batch` onto the data type of the struct `T`, selecting columns by field name.
+///
+/// The columns of `batch` are treated as the children of a struct, see [`arrow_array_project`].
+pub fn record_batch_project<T: ArrowField>(batch: &RecordBatch) -> Result<ArrayRef, ArrowError> {
+    arrow_array_project::<T>(&StructArray::from(batch.clone()))
+}
+
+fn project(expected: &DataType, array: &dyn Array, path: &str) -> Result<ArrayRef, ArrowError> {
+    match (expected, array.data_type()) {
+        // a full-length slice is a cheap way to obtain an `ArrayRef` sharing the buffers of `array`
+        (expected, actual) if expected == actual => Ok(array.slice(0, array.len())),
+        (DataType::Struct(fields), DataType::Struct(_)) => {
+            let array = array.as_struct();
+            let columns = fields
+                .iter()
+                .map(|field| {
+                    let path = match path {
+                        "" => field.name().to_string(),
+                        _ => format!("{path}.{}", field.name()),
+                    };
+                    let (index, actual) = array.fields().find(field.name()).ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!("Projection failed, field '{path}' not found"))
+                    })?;
+                    check_field_extension(field, actual, &path)?;
+                    project(field.data_type(), array.column(index).as_ref(), &path)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Arc::new(StructArray::try_new(
+                fields.clone(),
+                columns,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::List(field), DataType::List(actual)) => {
+            let path = format!("{path}[]");
+            check_field_extension(field, actual, &path)?;
+            let array = array.as_list::<i32>();
+            let values = project(field.data_type(), array.values().as_ref(), &path)?;
+            Ok(Arc::new(ListArray::try_new(
+                field.clone(),
+                array.offsets().clone(),
+                values,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::LargeList(field), DataType::LargeList(actual)) => {
+            let path = format!("{path}[]");
+            check_field_extension(field, actual, &path)?;
+            let array = array.as_list::<i64>();
+            let values = project(field.data_type(), array.values().as_ref(), &path)?;
+            Ok(Arc::new(LargeListArray::try_new(
+                field.clone(),
+                array.offsets().clone(),
+                values,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::FixedSizeList(field, size), DataType::FixedSizeList(actual, actual_size)) if size == actual_size => {
+            let path = format!("{path}[]");
+            check_field_extension(field, actual, &path)?;
+            let array = array.as_fixed_size_list();
+            let values = project(field.data_type(), array.values().as_ref(), &path)?;
+            Ok(Arc::new(FixedSizeListArray::try_new(
+                field.clone(),
+                *size,
+                values,
+                array.nulls().cloned(),
+            )?))
+        }
+        (expected, actual) => Err(ArrowError::InvalidArgumentError(format!(
+            "Projection failed for field '{path}'. Expected type={expected:#?}, but was type={actual:#?}"
+        ))),
+    }
+}
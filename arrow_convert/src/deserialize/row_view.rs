@@ -0,0 +1,19 @@
+/// A view of a single row of a struct array, generated for structs deriving `ArrowDeserialize` with
+/// `#[arrow_field(row_view)]`.
+///
+/// The view also has an inherent accessor per field, which deserializes only that field of the row. The methods of
+/// this trait are prefixed with `row_` and only shadowed by fields of the same name, in which case they can still be
+/// called as `RowView::row_index(&row)`.
+pub trait RowView {
+    /// The struct a row deserializes to.
+    type Owned;
+
+    /// The index of the row in the underlying array.
+    fn row_index(&self) -> usize;
+
+    /// Whether the row is null. Field accessors must not be used on null rows.
+    fn row_is_null(&self) -> bool;
+
+    /// Deserializes the whole row, or returns `None` if the row is null.
+    fn row_to_owned(&self) -> Option<Self::Owned>;
+}
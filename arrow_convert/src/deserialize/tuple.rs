@@ -0,0 +1,160 @@
+use std::marker::PhantomData;
+
+use arrow_array::{Array, StructArray};
+use arrow_buffer::bit_iterator::BitIterator;
+
+use super::{ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::ArrowField;
+
+// Macro to facilitate implementation of tuple deserialization. Each arity gets a placeholder array type
+// and an iterator that zips the iterators of the `StructArray` children, mirroring derived structs.
+macro_rules! impl_tuple_array {
+    ($array:ident, $iter:ident; $($name:ident: $field:ident: $idx:tt),+) => {
+        /// Internal `ArrowArray` helper to iterate over a `StructArray` as tuples.
+        pub struct $array<$($name),+>(PhantomData<($($name,)+)>);
+
+        /// Iterator over the tuples of a `StructArray`.
+        pub struct $iter<'a, $($name),+>
+        where
+            $($name: ArrowDeserialize + 'static,)+
+            $(<$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,)+
+        {
+            $($field: <<$name as ArrowDeserialize>::ArrayType as ArrowArrayIterable>::Iter<'a>,)+
+            validity: Option<BitIterator<'a>>,
+        }
+
+        impl<'a, $($name),+> Iterator for $iter<'a, $($name),+>
+        where
+            $($name: ArrowDeserialize + 'static,)+
+            $(<$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,)+
+        {
+            type Item = Option<($(<$name as ArrowField>::Type,)+)>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                $(let $field = self.$field.next()?;)+
+                let is_valid = match &mut self.validity {
+                    Some(validity) => validity.next()?,
+                    None => true,
+                };
+                if is_valid {
+                    Some(Some(($(<$name as ArrowDeserialize>::arrow_deserialize_internal($field),)+)))
+                } else {
+                    Some(None)
+                }
+            }
+        }
+
+        impl<$($name),+> ArrowArrayIterable for $array<$($name),+>
+        where
+            $($name: ArrowDeserialize + 'static,)+
+            $(<$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,)+
+        {
+            type Item<'a> = Option<($(<$name as ArrowField>::Type,)+)>;
+
+            type Iter<'a> = $iter<'a, $($name),+>;
+
+            fn iter(&self) -> Self::Iter<'_> {
+                unimplemented!("Use iter_from_array_ref");
+            }
+        }
+
+        impl<$($name),+> ArrowArray for $array<$($name),+>
+        where
+            $($name: ArrowDeserialize + 'static,)+
+            $(<$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,)+
+        {
+            type BaseArrayType = StructArray;
+
+            #[inline]
+            fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+                let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+                let values = arr.columns();
+                $iter {
+                    $($field: <<$name as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(
+                        values[$idx].as_ref(),
+                    ),)+
+                    validity: arr.nulls().map(|x| x.iter()),
+                }
+            }
+
+            #[inline]
+            fn value_from_array_ref(b: &dyn Array, index: usize) -> <Self as ArrowArrayIterable>::Item<'_> {
+                let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+                if arr.is_null(index) {
+                    return None;
+                }
+                let values = arr.columns();
+                Some(($(<$name as ArrowDeserialize>::arrow_deserialize_internal(
+                    <<$name as ArrowDeserialize>::ArrayType as ArrowArray>::value_from_array_ref(values[$idx].as_ref(), index),
+                ),)+))
+            }
+        }
+
+        impl<$($name),+> ArrowDeserialize for ($($name,)+)
+        where
+            $($name: ArrowDeserialize + 'static,)+
+            $(<$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,)+
+        {
+            type ArrayType = $array<$($name),+>;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<<Self as ArrowField>::Type>) -> Option<<Self as ArrowField>::Type> {
+                v
+            }
+
+            fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+                if let Some(arr) = arr.as_any().downcast_ref::<StructArray>() {
+                    let values = arr.columns();
+                    $(<$name as ArrowDeserialize>::validate_array(values[$idx].as_ref())?;)+
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple_array!(Tuple1Array, Tuple1ArrayIterator; A: field_0: 0);
+impl_tuple_array!(Tuple2Array, Tuple2ArrayIterator; A: field_0: 0, B: field_1: 1);
+impl_tuple_array!(Tuple3Array, Tuple3ArrayIterator; A: field_0: 0, B: field_1: 1, C: field_2: 2);
+impl_tuple_array!(
+    Tuple4Array, Tuple4ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3
+);
+impl_tuple_array!(
+    Tuple5Array, Tuple5ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4
+);
+impl_tuple_array!(
+    Tuple6Array, Tuple6ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5
+);
+impl_tuple_array!(
+    Tuple7Array, Tuple7ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6
+);
+impl_tuple_array!(
+    Tuple8Array, Tuple8ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7
+);
+impl_tuple_array!(
+    Tuple9Array, Tuple9ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8
+);
+impl_tuple_array!(
+    Tuple10Array, Tuple10ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9
+);
+impl_tuple_array!(
+    Tuple11Array, Tuple11ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9, K: field_10: 10
+);
+impl_tuple_array!(
+    Tuple12Array, Tuple12ArrayIterator;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9, K: field_10: 10, L: field_11: 11
+);
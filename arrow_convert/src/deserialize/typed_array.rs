@@ -0,0 +1,141 @@
+use std::marker::PhantomData;
+
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+
+use super::{check_array, check_extension_type, ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::ArrowField;
+
+/// An [`ArrayRef`] whose data type was checked against `T`, offering random access to deserialized elements.
+///
+/// The data type is checked once on construction. Afterwards [`TypedArray::get`] and [`TypedArray::value`]
+/// deserialize a single element in O(1) without walking an iterator, and [`TypedArray::slice`] is zero-copy.
+///
+/// As with [`arrow_array_deserialize_iterator_as_type`](super::arrow_array_deserialize_iterator_as_type),
+/// `T` can be a placeholder type such as [`LargeString`](crate::field::LargeString), in which case elements
+/// are of type `<T as ArrowField>::Type`.
+///
+/// ```rust
+/// # use arrow::array::ArrayRef;
+/// # use arrow_convert::deserialize::TypedArray;
+/// # use arrow_convert::serialize::TryIntoArrow;
+/// let array: ArrayRef = vec![Some(1_i64), None, Some(3)].try_into_arrow().unwrap();
+/// let typed = TypedArray::<Option<i64>>::try_new(array).unwrap();
+/// assert_eq!(typed.get(2), Some(Some(3)));
+/// assert_eq!(typed.value(1), None);
+/// assert_eq!(typed.get(3), None);
+/// ```
+pub struct TypedArray<T> {
+    array: ArrayRef,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedArray<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    /// Wraps `array`, checking that its data type matches `T` and that its values can be deserialized.
+    pub fn try_new(array: ArrayRef) -> Result<Self, ArrowError> {
+        check_array::<T>(array.as_ref())?;
+        Ok(Self {
+            array,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Same as [`TypedArray::try_new`], but also checks the extension name of `field`, the field describing
+    /// `array`, with [`check_extension_type`](super::check_extension_type).
+    pub fn try_new_with_field(field: &arrow_schema::Field, array: ArrayRef) -> Result<Self, ArrowError> {
+        check_extension_type::<T>(field)?;
+        Self::try_new(array)
+    }
+
+    /// The number of elements in the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Whether the array has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.array.is_empty()
+    }
+
+    /// Whether the element at `index` is null.
+    #[inline]
+    pub fn is_null(&self, index: usize) -> bool {
+        self.array.is_null(index)
+    }
+
+    /// Deserializes the element at `index`, or returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<<T as ArrowField>::Type> {
+        (index < self.len()).then(|| self.value(index))
+    }
+
+    /// Deserializes the element at `index` without checking it against the length of the array.
+    ///
+    /// This is the fast path for tight loops over known-valid indices. Out of bounds access panics inside
+    /// arrow, as does a null element when `T` is not an `Option`.
+    #[inline]
+    pub fn value(&self, index: usize) -> <T as ArrowField>::Type {
+        <T as ArrowDeserialize>::arrow_deserialize_internal(
+            <<T as ArrowDeserialize>::ArrayType as ArrowArray>::value_from_array_ref(self.array.as_ref(), index),
+        )
+    }
+
+    /// Returns a zero-copy slice of this array.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            array: self.array.slice(offset, length),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that deserializes every element of the array.
+    pub fn iter(&self) -> impl Iterator<Item = <T as ArrowField>::Type> + '_ {
+        <<T as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(self.array.as_ref())
+            .map(<T as ArrowDeserialize>::arrow_deserialize_internal)
+    }
+
+    /// The underlying array.
+    #[inline]
+    pub fn array(&self) -> &ArrayRef {
+        &self.array
+    }
+
+    /// Consumes the wrapper and returns the underlying array.
+    #[inline]
+    pub fn into_inner(self) -> ArrayRef {
+        self.array
+    }
+}
+
+impl<T> TryFrom<ArrayRef> for TypedArray<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type Error = ArrowError;
+
+    fn try_from(array: ArrayRef) -> Result<Self, Self::Error> {
+        Self::try_new(array)
+    }
+}
+
+impl<T> Clone for TypedArray<T> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for TypedArray<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedArray").field("array", &self.array).finish()
+    }
+}
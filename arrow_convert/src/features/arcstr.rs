@@ -0,0 +1,3 @@
+//! arcstr feature module, mapping `ArcStr` to `Utf8`
+
+impl_utf8_type!(arcstr::ArcStr);
@@ -0,0 +1,63 @@
+//! bytes feature module, mapping `Bytes` to `Binary`
+
+use arrow_array::builder::BinaryBuilder;
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, DataType};
+use bytes::Bytes;
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::{ArrowDeserialize, BufferSliceBinaryArray};
+use crate::field::ArrowField;
+use crate::serialize::{check_offset_overflow, ArrowSerialize};
+
+// Keeps a slice of an arrow value buffer alive for as long as the `Bytes` referencing it.
+struct BufferOwner(Buffer);
+
+impl AsRef<[u8]> for BufferOwner {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl ArrowField for Bytes {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+}
+
+arrow_enable_vec_for_type!(Bytes);
+
+impl ArrowSerialize for Bytes {
+    type ArrayBuilderType = BinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+// Deserialized values share the value buffer of the array instead of being copied.
+impl ArrowDeserialize for Bytes {
+    type ArrayType = BufferSliceBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Buffer>) -> Option<Self> {
+        v.map(|buffer| Bytes::from_owner(BufferOwner(buffer)))
+    }
+}
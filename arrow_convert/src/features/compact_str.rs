@@ -0,0 +1,3 @@
+//! compact_str feature module, mapping `CompactString` to `Utf8`
+
+impl_utf8_type!(compact_str::CompactString);
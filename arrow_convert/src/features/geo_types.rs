@@ -0,0 +1,402 @@
+use std::sync::Arc;
+
+use arrow_array::builder::{ArrayBuilder, FixedSizeListBuilder, Float64Builder, ListBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, ArrayRef, FixedSizeListArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use geo_types::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+use crate::deserialize::{ArrowDeserialize, Tuple2Array};
+use crate::field::{ArrowExtension, ArrowField, GeoArrow, GeoCrs, InterleavedCoord, SeparatedCoord};
+use crate::json::json_string;
+use crate::serialize::{check_offset_overflow, ArrowSerialize, Tuple2Builder};
+
+impl ArrowSerialize for InterleavedCoord {
+    type ArrayBuilderType = FixedSizeListBuilder<Float64Builder>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::new_array_with_capacity(0)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let field = Field::new("xy", DataType::Float64, false);
+        FixedSizeListBuilder::with_capacity(Float64Builder::with_capacity(capacity * 2), 2, capacity).with_field(field)
+    }
+
+    fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::FixedSizeList(child, 2) => {
+                FixedSizeListBuilder::with_capacity(Float64Builder::with_capacity(capacity * 2), 2, capacity)
+                    .with_field(child.clone())
+            }
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Coord<f64>, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        array.values().append_slice(&[v.x, v.y]);
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for InterleavedCoord {
+    type ArrayType = FixedSizeListArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<Coord<f64>> {
+        let v = v?;
+        let xy = v.as_primitive_opt::<Float64Type>()?;
+        Some(Coord {
+            x: xy.value(0),
+            y: xy.value(1),
+        })
+    }
+}
+
+impl ArrowSerialize for SeparatedCoord {
+    type ArrayBuilderType = Tuple2Builder<f64, f64>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::new_array_with_capacity(0)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let DataType::Struct(fields) = Self::data_type() else {
+            unreachable!("separated coordinates are always mapped to structs")
+        };
+        Tuple2Builder::try_with_fields(fields, capacity).unwrap_or_else(|e| unreachable!("{e}"))
+    }
+
+    fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::Struct(fields) => Tuple2Builder::try_with_fields(fields.clone(), capacity)
+                .unwrap_or_else(|_| Self::new_array_with_capacity(capacity)),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Coord<f64>, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        array.try_push(&(v.x, v.y))
+    }
+}
+
+impl ArrowDeserialize for SeparatedCoord {
+    type ArrayType = Tuple2Array<f64, f64>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<(f64, f64)>) -> Option<Coord<f64>> {
+        v.map(|(x, y)| Coord { x, y })
+    }
+}
+
+// Wraps `data_type` in a non-nullable `List` whose child is called `name`.
+fn list_of(name: &str, data_type: DataType) -> DataType {
+    DataType::List(Arc::new(Field::new(name, data_type, false)))
+}
+
+// Builds the GeoArrow extension metadata for the coordinate reference system `C`.
+fn geo_metadata<C: GeoCrs>() -> String {
+    let Some(crs) = C::crs() else {
+        return "{}".to_string();
+    };
+    let crs = match C::crs_type() {
+        // PROJJSON definitions are embedded as JSON objects rather than strings
+        Some("projjson") => crs,
+        _ => json_string(&crs),
+    };
+    match C::crs_type() {
+        Some(crs_type) => format!(r#"{{"crs":{crs},"crs_type":{}}}"#, json_string(crs_type)),
+        None => format!(r#"{{"crs":{crs}}}"#),
+    }
+}
+
+// Serializes polygon rings or linestrings as a list of coordinate lists.
+fn serialize_rings<'a, L>(
+    rings: impl IntoIterator<Item = &'a LineString<f64>>,
+    len: usize,
+    array: &mut ListBuilder<ListBuilder<<L as ArrowSerialize>::ArrayBuilderType>>,
+) -> Result<(), ArrowError>
+where
+    L: ArrowSerialize + ArrowField<Type = Coord<f64>> + crate::field::ArrowEnableVecForType + 'static,
+{
+    let values = array.values();
+    check_offset_overflow(values.len(), len)?;
+    for ring in rings {
+        <Vec<L> as ArrowSerialize>::arrow_serialize(&ring.0, values)?;
+    }
+    array.append(true);
+    Ok(())
+}
+
+// Rebuilds a polygon from its rings, the first of which is the exterior.
+fn polygon_from_rings(rings: Vec<Vec<Coord<f64>>>) -> Polygon<f64> {
+    let mut rings = rings.into_iter().map(LineString::from);
+    let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+    Polygon::new(exterior, rings.collect())
+}
+
+/// Implements `ArrowField`, `ArrowExtension`, `ArrowSerialize` and `ArrowDeserialize` for a geometry stored with
+/// the coordinate layout `$layout`.
+///
+/// - `$geometry`: The `geo-types` geometry type.
+/// - `$name`: The GeoArrow extension name.
+/// - `$storage`: The arrow_convert type the geometry is stored as, used to build and deserialize the array.
+/// - `$data_type`: The storage data type, with the child names required by GeoArrow.
+/// - `$se`: A closure that serializes the geometry into the storage builder.
+/// - `$de`: A closure that rebuilds the geometry from the deserialized storage value.
+macro_rules! impl_geometry {
+    ($layout:ty, $geometry:ty, $name:literal, $storage:ty, $data_type:expr, $se:expr, $de:expr) => {
+        impl<C: GeoCrs> ArrowField for GeoArrow<$geometry, C, $layout> {
+            type Type = $geometry;
+
+            #[inline]
+            fn data_type() -> DataType {
+                $data_type
+            }
+
+            #[inline]
+            fn field(name: &str) -> Field {
+                <Self as ArrowExtension>::extension_field(name)
+            }
+        }
+
+        impl<C: GeoCrs> ArrowExtension for GeoArrow<$geometry, C, $layout> {
+            const NAME: &'static str = $name;
+
+            fn metadata() -> Option<String> {
+                Some(geo_metadata::<C>())
+            }
+        }
+
+        impl<C: GeoCrs> crate::field::ArrowEnableVecForType for GeoArrow<$geometry, C, $layout> {}
+
+        impl<C: GeoCrs + 'static> ArrowSerialize for GeoArrow<$geometry, C, $layout> {
+            type ArrayBuilderType = <$storage as ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::new_array_with_capacity(0)
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                <$storage as ArrowSerialize>::new_array_for_field(&Self::field(""), capacity)
+            }
+
+            #[inline]
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                <$storage as ArrowSerialize>::new_array_for_field(field, capacity)
+            }
+
+            fn arrow_serialize(v: &$geometry, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+                #[allow(clippy::redundant_closure_call)]
+                $se(v, array)
+            }
+        }
+
+        impl<C: GeoCrs + 'static> ArrowDeserialize for GeoArrow<$geometry, C, $layout> {
+            type ArrayType = <$storage as ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(
+                v: <Self::ArrayType as crate::deserialize::ArrowArrayIterable>::Item<'_>,
+            ) -> Option<$geometry> {
+                #[allow(clippy::redundant_closure_call)]
+                <$storage as ArrowDeserialize>::arrow_deserialize(v).map($de)
+            }
+
+            #[inline]
+            fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+                <$storage as ArrowDeserialize>::validate_array(arr)
+            }
+        }
+    };
+}
+
+/// Implements the GeoArrow mappings of every supported geometry for the coordinate layout `$layout`.
+macro_rules! impl_geometries {
+    ($layout:ty) => {
+        impl_geometry!(
+            $layout,
+            Point<f64>,
+            "geoarrow.point",
+            $layout,
+            <$layout as ArrowField>::data_type(),
+            |v: &Point<f64>, array| <$layout as ArrowSerialize>::arrow_serialize(&v.0, array),
+            Point::from
+        );
+
+        impl_geometry!(
+            $layout,
+            LineString<f64>,
+            "geoarrow.linestring",
+            Vec<$layout>,
+            list_of("vertices", <$layout as ArrowField>::data_type()),
+            |v: &LineString<f64>, array| <Vec<$layout> as ArrowSerialize>::arrow_serialize(&v.0, array),
+            LineString::from
+        );
+
+        impl_geometry!(
+            $layout,
+            Polygon<f64>,
+            "geoarrow.polygon",
+            Vec<Vec<$layout>>,
+            list_of(
+                "rings",
+                list_of("vertices", <$layout as ArrowField>::data_type())
+            ),
+            |v: &Polygon<f64>, array| serialize_rings::<$layout>(
+                std::iter::once(v.exterior()).chain(v.interiors()),
+                1 + v.interiors().len(),
+                array
+            ),
+            polygon_from_rings
+        );
+
+        impl_geometry!(
+            $layout,
+            MultiPoint<f64>,
+            "geoarrow.multipoint",
+            Vec<$layout>,
+            list_of("points", <$layout as ArrowField>::data_type()),
+            |v: &MultiPoint<f64>, array: &mut <Vec<$layout> as ArrowSerialize>::ArrayBuilderType| {
+                let values = array.values();
+                check_offset_overflow(values.len(), v.0.len())?;
+                for point in &v.0 {
+                    <$layout as ArrowSerialize>::arrow_serialize(&point.0, values)?;
+                }
+                array.append(true);
+                Ok(())
+            },
+            |v: Vec<Coord<f64>>| MultiPoint::new(v.into_iter().map(Point::from).collect())
+        );
+
+        impl_geometry!(
+            $layout,
+            MultiLineString<f64>,
+            "geoarrow.multilinestring",
+            Vec<Vec<$layout>>,
+            list_of(
+                "linestrings",
+                list_of("vertices", <$layout as ArrowField>::data_type())
+            ),
+            |v: &MultiLineString<f64>, array| serialize_rings::<$layout>(&v.0, v.0.len(), array),
+            |v: Vec<Vec<Coord<f64>>>| MultiLineString::new(v.into_iter().map(LineString::from).collect())
+        );
+
+        impl_geometry!(
+            $layout,
+            MultiPolygon<f64>,
+            "geoarrow.multipolygon",
+            Vec<Vec<Vec<$layout>>>,
+            list_of(
+                "polygons",
+                list_of(
+                    "rings",
+                    list_of("vertices", <$layout as ArrowField>::data_type())
+                )
+            ),
+            |v: &MultiPolygon<f64>, array: &mut <Vec<Vec<Vec<$layout>>> as ArrowSerialize>::ArrayBuilderType| {
+                let values = array.values();
+                check_offset_overflow(values.len(), v.0.len())?;
+                for polygon in &v.0 {
+                    serialize_rings::<$layout>(
+                        std::iter::once(polygon.exterior()).chain(polygon.interiors()),
+                        1 + polygon.interiors().len(),
+                        values,
+                    )?;
+                }
+                array.append(true);
+                Ok(())
+            },
+            |v: Vec<Vec<Vec<Coord<f64>>>>| MultiPolygon::new(v.into_iter().map(polygon_from_rings).collect())
+        );
+    };
+}
+
+impl_geometries!(InterleavedCoord);
+impl_geometries!(SeparatedCoord);
+
+/// Maps a `geo-types` geometry to `GeoArrow` with an unknown CRS and interleaved coordinates.
+macro_rules! impl_default_geometry {
+    ($geometry:ty) => {
+        impl ArrowField for $geometry {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> DataType {
+                <GeoArrow<Self> as ArrowField>::data_type()
+            }
+
+            #[inline]
+            fn field(name: &str) -> Field {
+                <GeoArrow<Self> as ArrowField>::field(name)
+            }
+        }
+
+        impl ArrowExtension for $geometry {
+            const NAME: &'static str = <GeoArrow<Self> as ArrowExtension>::NAME;
+
+            fn metadata() -> Option<String> {
+                <GeoArrow<Self> as ArrowExtension>::metadata()
+            }
+        }
+
+        impl crate::field::ArrowEnableVecForType for $geometry {}
+
+        impl ArrowSerialize for $geometry {
+            type ArrayBuilderType = <GeoArrow<Self> as ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <GeoArrow<Self> as ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                <GeoArrow<Self> as ArrowSerialize>::new_array_with_capacity(capacity)
+            }
+
+            #[inline]
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                <GeoArrow<Self> as ArrowSerialize>::new_array_for_field(field, capacity)
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+                <GeoArrow<Self> as ArrowSerialize>::arrow_serialize(v, array)
+            }
+        }
+
+        impl ArrowDeserialize for $geometry {
+            type ArrayType = <GeoArrow<Self> as ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(
+                v: <Self::ArrayType as crate::deserialize::ArrowArrayIterable>::Item<'_>,
+            ) -> Option<Self> {
+                <GeoArrow<Self> as ArrowDeserialize>::arrow_deserialize(v)
+            }
+
+            #[inline]
+            fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+                <GeoArrow<Self> as ArrowDeserialize>::validate_array(arr)
+            }
+        }
+    };
+}
+
+impl_default_geometry!(Point<f64>);
+impl_default_geometry!(LineString<f64>);
+impl_default_geometry!(Polygon<f64>);
+impl_default_geometry!(MultiPoint<f64>);
+impl_default_geometry!(MultiLineString<f64>);
+impl_default_geometry!(MultiPolygon<f64>);
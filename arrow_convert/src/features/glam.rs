@@ -1,17 +1,21 @@
-use arrow_schema::DataType;
+use arrow_schema::{ArrowError, DataType, Fields};
 
 use crate::arrow_enable_vec_for_type;
-use crate::deserialize::ArrowDeserialize;
-use crate::field::ArrowField;
-use crate::serialize::ArrowSerialize;
+use crate::deserialize::{ArrowDeserialize, Tuple2Array, Tuple3Array, Tuple4Array};
+use crate::field::{ArrowField, RowMajor, StructVec};
+use crate::serialize::{ArrowSerialize, Tuple2Builder, Tuple3Builder, Tuple4Builder};
 use arrow_schema::Field;
 
 use crate::deserialize::arrow_deserialize_vec_helper;
-use arrow_array::builder::{BooleanBuilder, Float32Builder, Float64Builder};
+use arrow_array::builder::{BooleanBuilder, Float32Builder, Float64Builder, Int32Builder, Int64Builder, UInt32Builder};
 use arrow_array::ArrayRef;
 use arrow_array::{builder::FixedSizeListBuilder, FixedSizeListArray};
 use std::sync::Arc;
 
+/// Name of the child field of the `FixedSizeList` vectors and matrices are stored as. Builders created for a declared
+/// field keep the child name of that field, but reading expects this name.
+const ITEM_NAME: &str = "scalar";
+
 /// This macro implements the `ArrowSerialize` and `ArrowDeserialize` traits for a given `glam` vector or matrix type.
 ///
 /// The macro takes the following parameters:
@@ -28,7 +32,7 @@ macro_rules! impl_glam_ty {
             type Type = Self;
 
             fn data_type() -> DataType {
-                let field = Field::new("scalar", $arrow_dt, false);
+                let field = Field::new(ITEM_NAME, $arrow_dt, false);
                 DataType::FixedSizeList(Arc::new(field), $size)
             }
         }
@@ -39,10 +43,26 @@ macro_rules! impl_glam_ty {
             type ArrayBuilderType = FixedSizeListBuilder<$array_builder>;
 
             fn new_array() -> Self::ArrayBuilderType {
-                let field = Field::new("scalar", $arrow_dt, false);
+                let field = Field::new(ITEM_NAME, $arrow_dt, false);
                 Self::ArrayBuilderType::new(<$dt as ArrowSerialize>::new_array(), $size).with_field(field)
             }
 
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                let field = Field::new(ITEM_NAME, $arrow_dt, false);
+                let values = <$dt as ArrowSerialize>::new_array_with_capacity(capacity * $size as usize);
+                Self::ArrayBuilderType::with_capacity(values, $size, capacity).with_field(field)
+            }
+
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                match field.data_type() {
+                    DataType::FixedSizeList(child, _) => {
+                        let values = <$dt as ArrowSerialize>::new_array_with_capacity(capacity * $size as usize);
+                        Self::ArrayBuilderType::with_capacity(values, $size, capacity).with_field(child.clone())
+                    }
+                    _ => Self::new_array_with_capacity(capacity),
+                }
+            }
+
             fn arrow_serialize(
                 v: &Self::Type,
                 array: &mut Self::ArrayBuilderType,
@@ -153,6 +173,184 @@ macro_rules! impl_glam_mat_f64 {
     };
 }
 
+/// Implements the `ArrowSerialize` and `ArrowDeserialize` traits for glam vector and quaternion types that convert
+/// to and from arrays of their components, such as `glam::IVec3`, `glam::Vec3A` and `glam::Quat`.
+macro_rules! impl_glam_vec {
+    ($type:ty, $size:expr, $dt:ident, $arrow_dt:expr, $array_builder:ident) => {
+        impl_glam_ty!(
+            $type,
+            $size,
+            $dt,
+            $arrow_dt,
+            $array_builder,
+            |v: &$type| v.to_array(),
+            |v: Vec<$dt>| Self::from_slice(&v)
+        );
+    };
+}
+
+/// Implements the `ArrowSerialize` and `ArrowDeserialize` traits for the given glam affine transform types, stored
+/// column-major with the translation as the last column.
+macro_rules! impl_glam_affine {
+    ($type:ty, $size:expr, $dt:ident, $arrow_dt:expr, $array_builder:ident) => {
+        impl_glam_ty!(
+            $type,
+            $size,
+            $dt,
+            $arrow_dt,
+            $array_builder,
+            |v: &$type| v.to_cols_array(),
+            |v: Vec<$dt>| Self::from_cols_slice(&v)
+        );
+    };
+}
+
+/// Implements the `ArrowField`, `ArrowSerialize` and `ArrowDeserialize` traits for `RowMajor<$type>`, which stores
+/// the transpose of the matrix in the layout of `$type`.
+macro_rules! impl_glam_row_major {
+    ($type:ty) => {
+        impl ArrowField for RowMajor<$type> {
+            type Type = $type;
+
+            #[inline]
+            fn data_type() -> DataType {
+                <$type as ArrowField>::data_type()
+            }
+        }
+
+        impl ArrowSerialize for RowMajor<$type> {
+            type ArrayBuilderType = <$type as ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <$type as ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                <$type as ArrowSerialize>::new_array_with_capacity(capacity)
+            }
+
+            #[inline]
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                <$type as ArrowSerialize>::new_array_for_field(field, capacity)
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &$type, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+                <$type as ArrowSerialize>::arrow_serialize(&v.transpose(), array)
+            }
+        }
+
+        impl ArrowDeserialize for RowMajor<$type> {
+            type ArrayType = FixedSizeListArray;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<ArrayRef>) -> Option<$type> {
+                <$type as ArrowDeserialize>::arrow_deserialize(v).map(|m| m.transpose())
+            }
+        }
+    };
+}
+
+/// Implements the `ArrowField`, `ArrowSerialize` and `ArrowDeserialize` traits for `StructVec<$type>`, which stores
+/// each component of the vector in its own struct field.
+///
+/// The macro takes the following parameters:
+/// - `$type`: The type of the `glam` vector or quaternion.
+/// - `$dt`: The data type of the components.
+/// - `$builder`, `$array`: The tuple builder and array types used to store the components.
+/// - `$new`: A function that builds `$type` from its components.
+/// - `$component`: The names of the components, in order.
+macro_rules! impl_glam_struct {
+    (@component $component:ident, $dt:ty) => {
+        $dt
+    };
+    ($type:ty, $dt:ty, $builder:ty, $array:ty, $new:expr, $($component:ident),+) => {
+        impl ArrowField for StructVec<$type> {
+            type Type = $type;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Struct(Fields::from(vec![
+                    $(Field::new(stringify!($component), <$dt as ArrowField>::data_type(), false),)+
+                ]))
+            }
+        }
+
+        impl ArrowSerialize for StructVec<$type> {
+            type ArrayBuilderType = $builder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::new_array_with_capacity(0)
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                let DataType::Struct(fields) = Self::data_type() else {
+                    unreachable!("struct vectors are always mapped to structs")
+                };
+                <$builder>::try_with_fields(fields, capacity)
+                    .unwrap_or_else(|e| unreachable!("{e}"))
+            }
+
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                match field.data_type() {
+                    DataType::Struct(fields) => {
+                        <$builder>::try_with_fields(fields.clone(), capacity)
+                            .unwrap_or_else(|_| Self::new_array_with_capacity(capacity))
+                    }
+                    _ => Self::new_array_with_capacity(capacity),
+                }
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &$type, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+                array.try_push(&($(v.$component,)+))
+            }
+        }
+
+        impl ArrowDeserialize for StructVec<$type> {
+            type ArrayType = $array;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<($(impl_glam_struct!(@component $component, $dt),)+)>) -> Option<$type> {
+                let ($($component,)+) = v?;
+                Some($new($($component),+))
+            }
+        }
+    };
+}
+
+macro_rules! impl_glam_struct2 {
+    ($type:ty, $dt:ty, $new:expr) => {
+        impl_glam_struct!($type, $dt, Tuple2Builder<$dt, $dt>, Tuple2Array<$dt, $dt>, $new, x, y);
+    };
+}
+
+macro_rules! impl_glam_struct3 {
+    ($type:ty, $dt:ty, $new:expr) => {
+        impl_glam_struct!($type, $dt, Tuple3Builder<$dt, $dt, $dt>, Tuple3Array<$dt, $dt, $dt>, $new, x, y, z);
+    };
+}
+
+macro_rules! impl_glam_struct4 {
+    ($type:ty, $dt:ty, $new:expr) => {
+        impl_glam_struct!(
+            $type,
+            $dt,
+            Tuple4Builder<$dt, $dt, $dt, $dt>,
+            Tuple4Array<$dt, $dt, $dt, $dt>,
+            $new,
+            x,
+            y,
+            z,
+            w
+        );
+    };
+}
+
 // Boolean vectors
 impl_glam_vec_bool!(glam::BVec2, 2);
 impl_glam_vec_bool!(glam::BVec3, 3);
@@ -173,3 +371,53 @@ impl_glam_vec_f64!(glam::DVec4, 4);
 impl_glam_mat_f64!(glam::DMat2, 4);
 impl_glam_mat_f64!(glam::DMat3, 9);
 impl_glam_mat_f64!(glam::DMat4, 16);
+
+// Integer vectors
+impl_glam_vec!(glam::IVec2, 2, i32, DataType::Int32, Int32Builder);
+impl_glam_vec!(glam::IVec3, 3, i32, DataType::Int32, Int32Builder);
+impl_glam_vec!(glam::IVec4, 4, i32, DataType::Int32, Int32Builder);
+impl_glam_vec!(glam::UVec2, 2, u32, DataType::UInt32, UInt32Builder);
+impl_glam_vec!(glam::UVec3, 3, u32, DataType::UInt32, UInt32Builder);
+impl_glam_vec!(glam::UVec4, 4, u32, DataType::UInt32, UInt32Builder);
+impl_glam_vec!(glam::I64Vec2, 2, i64, DataType::Int64, Int64Builder);
+impl_glam_vec!(glam::I64Vec3, 3, i64, DataType::Int64, Int64Builder);
+impl_glam_vec!(glam::I64Vec4, 4, i64, DataType::Int64, Int64Builder);
+
+// SIMD-aligned vectors and quaternions, quaternions stored as [x, y, z, w]
+impl_glam_vec!(glam::Vec3A, 3, f32, DataType::Float32, Float32Builder);
+impl_glam_vec!(glam::Quat, 4, f32, DataType::Float32, Float32Builder);
+impl_glam_vec!(glam::DQuat, 4, f64, DataType::Float64, Float64Builder);
+
+// Affine transforms
+impl_glam_affine!(glam::Affine2, 6, f32, DataType::Float32, Float32Builder);
+impl_glam_affine!(glam::Affine3A, 12, f32, DataType::Float32, Float32Builder);
+impl_glam_affine!(glam::DAffine2, 6, f64, DataType::Float64, Float64Builder);
+impl_glam_affine!(glam::DAffine3, 12, f64, DataType::Float64, Float64Builder);
+
+// Row-major matrices
+impl_glam_row_major!(glam::Mat2);
+impl_glam_row_major!(glam::Mat3);
+impl_glam_row_major!(glam::Mat4);
+impl_glam_row_major!(glam::DMat2);
+impl_glam_row_major!(glam::DMat3);
+impl_glam_row_major!(glam::DMat4);
+
+// Vectors and quaternions stored as structs of their components
+impl_glam_struct2!(glam::Vec2, f32, glam::Vec2::new);
+impl_glam_struct3!(glam::Vec3, f32, glam::Vec3::new);
+impl_glam_struct4!(glam::Vec4, f32, glam::Vec4::new);
+impl_glam_struct3!(glam::Vec3A, f32, glam::Vec3A::new);
+impl_glam_struct2!(glam::DVec2, f64, glam::DVec2::new);
+impl_glam_struct3!(glam::DVec3, f64, glam::DVec3::new);
+impl_glam_struct4!(glam::DVec4, f64, glam::DVec4::new);
+impl_glam_struct2!(glam::IVec2, i32, glam::IVec2::new);
+impl_glam_struct3!(glam::IVec3, i32, glam::IVec3::new);
+impl_glam_struct4!(glam::IVec4, i32, glam::IVec4::new);
+impl_glam_struct2!(glam::UVec2, u32, glam::UVec2::new);
+impl_glam_struct3!(glam::UVec3, u32, glam::UVec3::new);
+impl_glam_struct4!(glam::UVec4, u32, glam::UVec4::new);
+impl_glam_struct2!(glam::I64Vec2, i64, glam::I64Vec2::new);
+impl_glam_struct3!(glam::I64Vec3, i64, glam::I64Vec3::new);
+impl_glam_struct4!(glam::I64Vec4, i64, glam::I64Vec4::new);
+impl_glam_struct4!(glam::Quat, f32, glam::Quat::from_xyzw);
+impl_glam_struct4!(glam::DQuat, f64, glam::DQuat::from_xyzw);
@@ -0,0 +1,207 @@
+//! Support for the temporal types of the `jiff` crate, mapped like the corresponding `chrono` types.
+
+use arrow_array::types::{Date32Type, DurationNanosecondType, TimestampNanosecondType};
+use arrow_array::{Array, Date32Array, DurationNanosecondArray, TimestampNanosecondArray};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+use jiff::civil::{Date, DateTime};
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Timestamp, Zoned};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::{validate_temporal_values, ArrowDeserialize};
+use crate::field::ArrowField;
+use crate::serialize::{impl_temporal_type, ArrowTemporal};
+
+const UNIX_EPOCH_DATE: Date = Date::constant(1970, 1, 1);
+
+const UNIX_EPOCH_DATETIME: DateTime = DateTime::constant(1970, 1, 1, 0, 0, 0, 0);
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// Saturates a nanosecond count at the bounds of i64.
+#[inline]
+fn clamp_nanos(ns: i128) -> i64 {
+    ns.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+impl ArrowField for Timestamp {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+}
+
+impl ArrowTemporal for Timestamp {
+    type ArrowType = TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        i64::try_from(v.as_nanosecond()).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        clamp_nanos(v.as_nanosecond())
+    }
+}
+
+impl ArrowDeserialize for Timestamp {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|ns| Timestamp::from_nanosecond(ns as i128).ok())
+    }
+}
+
+/// `Zoned` values are stored as UTC timestamps, so the time zone is not preserved and deserialized values are in UTC.
+impl ArrowField for Zoned {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+}
+
+impl ArrowTemporal for Zoned {
+    type ArrowType = TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        <Timestamp as ArrowTemporal>::to_native(&v.timestamp())
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        <Timestamp as ArrowTemporal>::to_native_clamped(&v.timestamp())
+    }
+}
+
+impl ArrowDeserialize for Zoned {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        <Timestamp as ArrowDeserialize>::arrow_deserialize(v).map(|t| t.to_zoned(TimeZone::UTC))
+    }
+}
+
+impl ArrowField for DateTime {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    }
+}
+
+impl ArrowTemporal for DateTime {
+    type ArrowType = TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        i64::try_from(v.duration_since(UNIX_EPOCH_DATETIME).as_nanos()).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        clamp_nanos(v.duration_since(UNIX_EPOCH_DATETIME).as_nanos())
+    }
+}
+
+impl ArrowDeserialize for DateTime {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|ns| UNIX_EPOCH_DATETIME.checked_add(SignedDuration::from_nanos(ns)).ok())
+    }
+}
+
+impl ArrowField for Date {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Date32
+    }
+}
+
+impl ArrowTemporal for Date {
+    type ArrowType = Date32Type;
+
+    // every `Date` is within the range of `Date32`
+    #[inline]
+    fn to_native(v: &Self) -> Option<i32> {
+        Some(Self::to_native_clamped(v))
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i32 {
+        (v.duration_since(UNIX_EPOCH_DATE).as_secs() / SECONDS_PER_DAY) as i32
+    }
+}
+
+impl ArrowDeserialize for Date {
+    type ArrayType = Date32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i32>) -> Option<Self> {
+        v.and_then(|days| {
+            UNIX_EPOCH_DATE
+                .checked_add(SignedDuration::from_secs(days as i64 * SECONDS_PER_DAY))
+                .ok()
+        })
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        validate_temporal_values::<Self, Date32Type>(arr, "jiff::civil::Date")
+    }
+}
+
+impl ArrowField for SignedDuration {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Duration(TimeUnit::Nanosecond)
+    }
+}
+
+impl ArrowTemporal for SignedDuration {
+    type ArrowType = DurationNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        i64::try_from(v.as_nanos()).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        clamp_nanos(v.as_nanos())
+    }
+}
+
+impl ArrowDeserialize for SignedDuration {
+    type ArrayType = DurationNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.map(SignedDuration::from_nanos)
+    }
+}
+
+impl_temporal_type!(Timestamp);
+impl_temporal_type!(Zoned);
+impl_temporal_type!(DateTime);
+impl_temporal_type!(Date);
+impl_temporal_type!(SignedDuration);
+
+arrow_enable_vec_for_type!(Timestamp);
+arrow_enable_vec_for_type!(Zoned);
+arrow_enable_vec_for_type!(DateTime);
+arrow_enable_vec_for_type!(Date);
+arrow_enable_vec_for_type!(SignedDuration);
@@ -1,11 +1,92 @@
+// Implements `ArrowField`, `ArrowSerialize` and `ArrowDeserialize` for a string type that derefs to `str` and can be
+// built from `&str`, mapping it to `Utf8` like `String`.
+#[cfg(any(feature = "smol_str", feature = "compact_str", feature = "arcstr"))]
+macro_rules! impl_utf8_type {
+    ($type:ty) => {
+        impl $crate::field::ArrowField for $type {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> arrow_schema::DataType {
+                arrow_schema::DataType::Utf8
+            }
+        }
+
+        $crate::arrow_enable_vec_for_type!($type);
+
+        impl $crate::serialize::ArrowSerialize for $type {
+            type ArrayBuilderType = arrow_array::builder::StringBuilder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::with_capacity(capacity, 0)
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+                $crate::serialize::check_offset_overflow(array.values_slice().len(), v.len())?;
+                array.append_value(v.as_str());
+                Ok(())
+            }
+        }
+
+        impl $crate::deserialize::ArrowDeserialize for $type {
+            type ArrayType = arrow_array::StringArray;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+                v.map(<$type>::from)
+            }
+        }
+    };
+}
+
 #[cfg(feature = "tinystr")]
 mod tinystr;
 
 #[cfg(feature = "rust_decimal")]
 mod rust_decimal;
 
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+#[cfg(feature = "geo-types")]
+mod geo_types;
+
 #[cfg(feature = "glam")]
 mod glam;
 
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "ndarray")]
+mod ndarray;
+
 #[cfg(feature = "uuid")]
 mod uuid;
+
+#[cfg(feature = "serde_json")]
+mod serde_json;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "jiff")]
+mod jiff;
+
+#[cfg(feature = "smol_str")]
+mod smol_str;
+
+#[cfg(feature = "compact_str")]
+mod compact_str;
+
+#[cfg(feature = "arcstr")]
+mod arcstr;
+
+#[cfg(feature = "bytes")]
+mod bytes;
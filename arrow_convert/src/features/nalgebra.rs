@@ -0,0 +1,130 @@
+use arrow_array::builder::FixedSizeListBuilder;
+use arrow_array::{ArrayRef, FixedSizeListArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use nalgebra::{
+    Isometry3, Point, Quaternion, SMatrix, SVector, Scalar, Translation3, Unit, UnitQuaternion, Vector3, Vector4,
+};
+use std::sync::Arc;
+
+use crate::deserialize::{arrow_deserialize_vec_helper, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::{ArrowEnableVecForType, ArrowField};
+use crate::serialize::ArrowSerialize;
+
+/// This macro implements the `ArrowField`, `ArrowSerialize` and `ArrowDeserialize` traits for a given `nalgebra` type
+/// with scalar type `T`, stored as a `FixedSizeList` of `T`.
+///
+/// The macro takes the following parameters:
+/// - `$generics`: The const generic parameters of the type, in addition to the scalar type `T`.
+/// - `$type`: The `nalgebra` type to implement the traits for.
+/// - `$size`: The number of scalars in the type (e.g. `R * C` for `SMatrix<T, R, C>`).
+/// - `$se`: An expression over `$v: &$type` that iterates over the scalars of the type, in storage order.
+/// - `$de`: An expression over `$d: Vec<T>` that builds the type from its scalars.
+macro_rules! impl_nalgebra_ty {
+    ([$($generics:tt)*], $type:ty, $size:expr, |$v:ident| $se:expr, |$d:ident| $de:expr) => {
+        impl<T, $($generics)*> ArrowField for $type
+        where
+            T: Scalar + ArrowField<Type = T> + ArrowEnableVecForType,
+        {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> DataType {
+                let field = <T as ArrowField>::field("scalar");
+                DataType::FixedSizeList(Arc::new(field), ($size) as i32)
+            }
+        }
+
+        impl<T, $($generics)*> ArrowEnableVecForType for $type
+        where
+            T: Scalar + ArrowField<Type = T> + ArrowEnableVecForType,
+        {
+        }
+
+        impl<T, $($generics)*> ArrowSerialize for $type
+        where
+            T: Scalar + ArrowField<Type = T> + ArrowEnableVecForType + ArrowSerialize,
+        {
+            type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::new_array_with_capacity(0)
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                let field = <T as ArrowField>::field("scalar");
+                let values = <T as ArrowSerialize>::new_array_with_capacity(capacity * ($size));
+                Self::ArrayBuilderType::with_capacity(values, ($size) as i32, capacity).with_field(field)
+            }
+
+            fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+                match field.data_type() {
+                    DataType::FixedSizeList(child, _) => {
+                        let values = <T as ArrowSerialize>::new_array_for_field(child, capacity * ($size));
+                        Self::ArrayBuilderType::with_capacity(values, ($size) as i32, capacity).with_field(child.clone())
+                    }
+                    _ => Self::new_array_with_capacity(capacity),
+                }
+            }
+
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+                let $v = v;
+                for x in $se {
+                    <T as ArrowSerialize>::arrow_serialize(x, array.values())?;
+                }
+                array.append(true);
+                Ok(())
+            }
+        }
+
+        impl<T, $($generics)*> ArrowDeserialize for $type
+        where
+            T: Scalar + ArrowField<Type = T> + ArrowEnableVecForType + ArrowDeserialize,
+            <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+        {
+            type ArrayType = FixedSizeListArray;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<ArrayRef>) -> Option<Self> {
+                let $d = arrow_deserialize_vec_helper::<T>(v)?;
+                Some($de)
+            }
+        }
+    };
+}
+
+// Vectors and matrices, stored column-major like nalgebra itself (`SVector<T, D>` is `SMatrix<T, D, 1>`)
+impl_nalgebra_ty!(
+    [const R: usize, const C: usize],
+    SMatrix<T, R, C>,
+    R * C,
+    |v| v.iter(),
+    |d| SMatrix::from_column_slice(&d)
+);
+
+// Points, stored as their coordinates
+impl_nalgebra_ty!(
+    [const D: usize],
+    Point<T, D>,
+    D,
+    |v| v.coords.iter(),
+    |d| Point::from(SVector::<T, D>::from_column_slice(&d))
+);
+
+// Unit quaternions, stored as [i, j, k, w]
+impl_nalgebra_ty!([], UnitQuaternion<T>, 4, |v| v.coords.iter(), |d| {
+    Unit::new_unchecked(Quaternion::from(Vector4::from_column_slice(&d)))
+});
+
+// Isometries, stored as the translation [x, y, z] followed by the rotation [i, j, k, w]
+impl_nalgebra_ty!(
+    [],
+    Isometry3<T>,
+    7,
+    |v| v.translation.vector.iter().chain(v.rotation.coords.iter()),
+    |d| Isometry3 {
+        translation: Translation3::from(Vector3::from_column_slice(&d[..3])),
+        rotation: Unit::new_unchecked(Quaternion::from(Vector4::from_column_slice(&d[3..]))),
+    }
+);
@@ -0,0 +1,190 @@
+use arrow_array::cast::AsArray;
+use arrow_array::{types::Int32Type, Array, StructArray};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use ndarray::{Array as NdArray, Dimension, IxDyn};
+
+use crate::deserialize::{validate_list_values, ArrowDeserialize, Tuple2Array};
+use crate::field::{ArrowEnableVecForType, ArrowExtension, ArrowField, FixedSizeVec, TensorData, VariableShapeTensor};
+use crate::serialize::{ArrowSerialize, Tuple2Builder};
+
+impl<E, D> TensorData for NdArray<E, D>
+where
+    E: TensorData<Element = E> + ArrowField<Type = E> + Clone,
+    D: Dimension,
+{
+    type Element = E;
+
+    #[inline]
+    fn tensor_shape(&self) -> Vec<usize> {
+        self.shape().to_vec()
+    }
+
+    #[inline]
+    fn try_for_each_element<F>(&self, f: &mut F) -> Result<(), ArrowError>
+    where
+        F: FnMut(&Self::Element) -> Result<(), ArrowError>,
+    {
+        self.iter().try_for_each(f)
+    }
+
+    fn from_tensor_elements<I>(shape: &[usize], elements: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = Self::Element>,
+    {
+        let len = shape.iter().product::<usize>();
+        let values = elements.take(len).collect::<Vec<_>>();
+        if values.len() != len {
+            return None;
+        }
+        NdArray::from_shape_vec(IxDyn(shape), values)
+            .ok()?
+            .into_dimensionality::<D>()
+            .ok()
+    }
+}
+
+// Returns an error if arrays of dimension `D` can't be stored with `ndim` dimensions.
+fn check_ndim<D: Dimension>(ndim: i32) -> Result<(), ArrowError> {
+    match D::NDIM {
+        Some(n) if n as i32 != ndim => Err(ArrowError::InvalidArgumentError(format!(
+            "VariableShapeTensor with {ndim} dimensions can't hold arrays of {n} dimensions"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl<E, D, const NDIM: i32> ArrowField for VariableShapeTensor<NdArray<E, D>, NDIM>
+where
+    E: ArrowField + ArrowEnableVecForType,
+    D: Dimension,
+{
+    type Type = NdArray<<E as ArrowField>::Type, D>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Struct(Fields::from(vec![
+            Field::new("data", <Vec<E> as ArrowField>::data_type(), false),
+            Field::new(
+                "shape",
+                <FixedSizeVec<i32, NDIM> as ArrowField>::data_type(),
+                false,
+            ),
+        ]))
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        <Self as ArrowExtension>::extension_field(name)
+    }
+}
+
+impl<E, D, const NDIM: i32> ArrowExtension for VariableShapeTensor<NdArray<E, D>, NDIM>
+where
+    E: ArrowField + ArrowEnableVecForType,
+    D: Dimension,
+{
+    const NAME: &'static str = "arrow.variable_shape_tensor";
+}
+
+impl<E, D, const NDIM: i32> ArrowEnableVecForType for VariableShapeTensor<NdArray<E, D>, NDIM>
+where
+    E: ArrowField + ArrowEnableVecForType,
+    D: Dimension,
+{
+}
+
+impl<E, D, const NDIM: i32> ArrowSerialize for VariableShapeTensor<NdArray<E, D>, NDIM>
+where
+    E: ArrowSerialize + ArrowField<Type = E> + ArrowEnableVecForType + Clone + 'static,
+    D: Dimension + 'static,
+{
+    type ArrayBuilderType = Tuple2Builder<Vec<E>, FixedSizeVec<i32, NDIM>>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::new_array_with_capacity(0)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let DataType::Struct(fields) = Self::data_type() else {
+            unreachable!("variable shape tensors are always mapped to structs")
+        };
+        Tuple2Builder::try_with_fields(fields, capacity).unwrap_or_else(|e| unreachable!("{e}"))
+    }
+
+    fn new_array_for_field(field: &Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::Struct(fields) => Tuple2Builder::try_with_fields(fields.clone(), capacity)
+                .unwrap_or_else(|_| Self::new_array_with_capacity(capacity)),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
+    fn arrow_serialize(v: &NdArray<E, D>, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        check_ndim::<D>(NDIM)?;
+        if v.ndim() as i32 != NDIM {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "VariableShapeTensor with {NDIM} dimensions can't hold an array of shape {:?}",
+                v.shape()
+            )));
+        }
+        let shape = v
+            .shape()
+            .iter()
+            .map(|&d| i32::try_from(d))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                ArrowError::InvalidArgumentError(format!("tensor shape {:?} does not fit in i32", v.shape()))
+            })?;
+        array.try_push(&(v.iter().cloned().collect(), shape))
+    }
+}
+
+impl<E, D, const NDIM: i32> ArrowDeserialize for VariableShapeTensor<NdArray<E, D>, NDIM>
+where
+    E: ArrowDeserialize + ArrowField<Type = E> + ArrowEnableVecForType + 'static,
+    <E as ArrowDeserialize>::ArrayType: 'static,
+    D: Dimension + 'static,
+{
+    type ArrayType = Tuple2Array<Vec<E>, FixedSizeVec<i32, NDIM>>;
+
+    fn arrow_deserialize(v: Option<(Vec<E>, Vec<i32>)>) -> Option<NdArray<E, D>> {
+        let (data, shape) = v?;
+        let shape = shape
+            .into_iter()
+            .map(|d| usize::try_from(d).ok())
+            .collect::<Option<Vec<_>>>()?;
+        NdArray::from_shape_vec(IxDyn(&shape), data)
+            .ok()?
+            .into_dimensionality::<D>()
+            .ok()
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        check_ndim::<D>(NDIM)?;
+        let Some(arr) = arr.as_any().downcast_ref::<StructArray>() else {
+            return Ok(());
+        };
+        let (Some(data), Some(shape)) = (
+            arr.column(0).as_list_opt::<i32>(),
+            arr.column(1).as_fixed_size_list_opt(),
+        ) else {
+            return Ok(());
+        };
+        for index in (0..arr.len()).filter(|&i| arr.is_valid(i)) {
+            let dims = shape.value(index);
+            let dims = dims.as_primitive::<Int32Type>().values();
+            let len = dims
+                .iter()
+                .try_fold(1_usize, |len, &d| len.checked_mul(usize::try_from(d).ok()?));
+            if len != Some(data.value_length(index) as usize) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "invalid VariableShapeTensor at index {index}: shape {dims:?} does not match {} data elements",
+                    data.value_length(index)
+                )));
+            }
+        }
+        validate_list_values::<E>(data)
+    }
+}
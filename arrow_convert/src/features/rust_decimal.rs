@@ -26,6 +26,10 @@ impl ArrowSerialize for Decimal {
         Decimal128Builder::new().with_data_type(Self::data_type())
     }
 
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Decimal128Builder::with_capacity(capacity).with_data_type(Self::data_type())
+    }
+
     fn arrow_serialize(v: &Self::Type, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_value(decimal_to_scaled_i128(*v));
         Ok(())
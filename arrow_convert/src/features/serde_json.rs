@@ -0,0 +1,175 @@
+use arrow_array::{builder::StringBuilder, Array, StringArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use serde::de::{DeserializeOwned, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::{ArrowExtension, ArrowField, Json};
+use crate::serialize::{check_offset_overflow, ArrowSerialize};
+
+impl ArrowField for Value {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        <Self as ArrowExtension>::extension_field(name)
+    }
+}
+
+impl ArrowExtension for Value {
+    const NAME: &'static str = "arrow.json";
+}
+
+arrow_enable_vec_for_type!(Value);
+
+// Appends the JSON text of `v` to `array`.
+fn serialize_json<T: Serialize>(v: &T, array: &mut StringBuilder) -> Result<(), ArrowError> {
+    let text = serde_json::to_string(v)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("failed to serialize JSON: {e}")))?;
+    check_offset_overflow(array.values_slice().len(), text.len())?;
+    array.append_value(text);
+    Ok(())
+}
+
+// Accepts the same JSON texts as `Value` without building the tree. Unlike `IgnoredAny`, it goes through
+// `deserialize_any`, so nesting deeper than the recursion limit of `serde_json` is rejected as it is for `Value`.
+struct WellFormed;
+
+impl<'de> Deserialize<'de> for WellFormed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(WellFormed)
+    }
+}
+
+impl<'de> Visitor<'de> for WellFormed {
+    type Value = WellFormed;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self, A::Error> {
+        while seq.next_element::<WellFormed>()?.is_some() {}
+        Ok(self)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self, A::Error> {
+        while map.next_entry::<IgnoredAny, WellFormed>()?.is_some() {}
+        Ok(self)
+    }
+}
+
+// Checks that every non-null value of a JSON array parses into `T`.
+fn validate_json<T: DeserializeOwned>(arr: &dyn Array) -> Result<(), ArrowError> {
+    let Some(arr) = arr.as_any().downcast_ref::<StringArray>() else {
+        return Ok(());
+    };
+    for (index, value) in arr.iter().enumerate() {
+        let Some(value) = value else { continue };
+        serde_json::from_str::<T>(value)
+            .map_err(|e| ArrowError::InvalidArgumentError(format!("invalid JSON at index {index}: {e}")))?;
+    }
+    Ok(())
+}
+
+impl ArrowSerialize for Value {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        StringBuilder::new()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        StringBuilder::with_capacity(capacity, 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        serialize_json(v, array)
+    }
+}
+
+impl ArrowDeserialize for Value {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.and_then(|t| serde_json::from_str(t).ok())
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        // values are parsed again when deserialized, avoid building each tree twice
+        validate_json::<WellFormed>(arr)
+    }
+}
+
+impl<T> ArrowSerialize for Json<T>
+where
+    T: Serialize + 'static,
+{
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        StringBuilder::new()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        StringBuilder::with_capacity(capacity, 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), ArrowError> {
+        serialize_json(&v.0, array)
+    }
+}
+
+impl<T> ArrowDeserialize for Json<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.and_then(|t| serde_json::from_str(t).ok()).map(Json)
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        validate_json::<T>(arr)
+    }
+}
@@ -0,0 +1,77 @@
+use arrow_array::ArrayRef;
+use arrow_array::ListArray;
+use arrow_schema::DataType;
+use smallvec::SmallVec;
+
+use crate::deserialize::{
+    arrow_deserialize_collection_helper, validate_list_values, ArrowArrayIterable, ArrowDeserialize,
+};
+use crate::field::{ArrowEnableVecForType, ArrowField};
+use crate::serialize::{check_offset_overflow, ArrowSerialize};
+use arrow_array::builder::ArrayBuilder;
+
+impl<T, const N: usize> ArrowField for SmallVec<[T; N]>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = SmallVec<[<T as ArrowField>::Type; N]>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+impl<T, const N: usize> ArrowEnableVecForType for SmallVec<[T; N]> where T: ArrowField + ArrowEnableVecForType {}
+
+impl<T, const N: usize> ArrowSerialize for SmallVec<[T; N]>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = <Vec<T> as ArrowSerialize>::ArrayBuilderType;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        <Vec<T> as ArrowSerialize>::new_array()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        <Vec<T> as ArrowSerialize>::new_array_with_capacity(capacity)
+    }
+
+    #[inline]
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        <Vec<T> as ArrowSerialize>::new_array_for_field(field, capacity)
+    }
+
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::ArrayBuilderType,
+    ) -> Result<(), arrow_schema::ArrowError> {
+        let values = array.values();
+        check_offset_overflow(values.len(), v.len())?;
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> ArrowDeserialize for SmallVec<[T; N]>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_collection_helper::<T, _>(v)
+    }
+
+    fn validate_array(arr: &dyn arrow_array::Array) -> Result<(), arrow_schema::ArrowError> {
+        validate_list_values::<T>(arr)
+    }
+}
@@ -0,0 +1,3 @@
+//! smol_str feature module, mapping `SmolStr` to `Utf8`
+
+impl_utf8_type!(smol_str::SmolStr);
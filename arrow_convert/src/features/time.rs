@@ -0,0 +1,213 @@
+//! Support for the temporal types of the `time` crate, mapped like the corresponding `chrono` types.
+
+use arrow_array::types::{Date32Type, DurationNanosecondType, Time64NanosecondType, TimestampNanosecondType};
+use arrow_array::{Array, Date32Array, DurationNanosecondArray, Time64NanosecondArray, TimestampNanosecondArray};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::{validate_temporal_values, ArrowDeserialize};
+use crate::field::ArrowField;
+use crate::serialize::{impl_temporal_type, ArrowTemporal};
+
+// Julian day number of the unix epoch
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+// Saturates a nanosecond count at the bounds of i64.
+#[inline]
+fn clamp_nanos(ns: i128) -> i64 {
+    ns.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+impl ArrowField for OffsetDateTime {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+}
+
+impl ArrowTemporal for OffsetDateTime {
+    type ArrowType = TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        i64::try_from(v.unix_timestamp_nanos()).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        clamp_nanos(v.unix_timestamp_nanos())
+    }
+}
+
+impl ArrowDeserialize for OffsetDateTime {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|ns| OffsetDateTime::from_unix_timestamp_nanos(ns as i128).ok())
+    }
+}
+
+impl ArrowField for PrimitiveDateTime {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    }
+}
+
+impl ArrowTemporal for PrimitiveDateTime {
+    type ArrowType = TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        <OffsetDateTime as ArrowTemporal>::to_native(&v.assume_utc())
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        <OffsetDateTime as ArrowTemporal>::to_native_clamped(&v.assume_utc())
+    }
+}
+
+impl ArrowDeserialize for PrimitiveDateTime {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        <OffsetDateTime as ArrowDeserialize>::arrow_deserialize(v).map(|t| PrimitiveDateTime::new(t.date(), t.time()))
+    }
+}
+
+impl ArrowField for Date {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Date32
+    }
+}
+
+impl ArrowTemporal for Date {
+    type ArrowType = Date32Type;
+
+    // every `Date` is within the range of `Date32`
+    #[inline]
+    fn to_native(v: &Self) -> Option<i32> {
+        Some(v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY)
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i32 {
+        v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY
+    }
+}
+
+impl ArrowDeserialize for Date {
+    type ArrayType = Date32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i32>) -> Option<Self> {
+        v.and_then(|days| Date::from_julian_day(days.checked_add(UNIX_EPOCH_JULIAN_DAY)?).ok())
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        validate_temporal_values::<Self, Date32Type>(arr, "time::Date")
+    }
+}
+
+impl ArrowField for Time {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Time64(TimeUnit::Nanosecond)
+    }
+}
+
+impl ArrowTemporal for Time {
+    type ArrowType = Time64NanosecondType;
+
+    // every `Time` is within the range of `Time64`
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        Some(Self::to_native_clamped(v))
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        let (hour, minute, second, nanosecond) = v.as_hms_nano();
+        (hour as i64 * 3600 + minute as i64 * 60 + second as i64) * 1_000_000_000 + nanosecond as i64
+    }
+}
+
+impl ArrowDeserialize for Time {
+    type ArrayType = Time64NanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        let ns = v.filter(|ns| (0..NANOSECONDS_PER_DAY).contains(ns))?;
+        let seconds = ns / 1_000_000_000;
+        Time::from_hms_nano(
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+            (ns % 1_000_000_000) as u32,
+        )
+        .ok()
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        validate_temporal_values::<Self, Time64NanosecondType>(arr, "time::Time")
+    }
+}
+
+impl ArrowField for Duration {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Duration(TimeUnit::Nanosecond)
+    }
+}
+
+impl ArrowTemporal for Duration {
+    type ArrowType = DurationNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        i64::try_from(v.whole_nanoseconds()).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        clamp_nanos(v.whole_nanoseconds())
+    }
+}
+
+impl ArrowDeserialize for Duration {
+    type ArrayType = DurationNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.map(Duration::nanoseconds)
+    }
+}
+
+impl_temporal_type!(OffsetDateTime);
+impl_temporal_type!(PrimitiveDateTime);
+impl_temporal_type!(Date);
+impl_temporal_type!(Time);
+impl_temporal_type!(Duration);
+
+arrow_enable_vec_for_type!(OffsetDateTime);
+arrow_enable_vec_for_type!(PrimitiveDateTime);
+arrow_enable_vec_for_type!(Date);
+arrow_enable_vec_for_type!(Time);
+arrow_enable_vec_for_type!(Duration);
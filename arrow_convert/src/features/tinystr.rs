@@ -1,11 +1,15 @@
-use arrow_schema::DataType;
+use arrow_array::{Array, StringArray};
+use arrow_schema::{ArrowError, DataType};
 use tinystr::TinyAsciiStr;
 
 use crate::deserialize::ArrowDeserialize;
-use crate::field::ArrowField;
-use crate::serialize::ArrowSerialize;
+use crate::field::{ArrowEnableVecForType, ArrowField, TinyAsciiStrUtf8};
+use crate::serialize::{check_offset_overflow, ArrowSerialize};
 
-use arrow_array::{builder::FixedSizeBinaryBuilder, FixedSizeBinaryArray};
+use arrow_array::{
+    builder::{FixedSizeBinaryBuilder, StringBuilder},
+    FixedSizeBinaryArray,
+};
 
 impl<const N: usize> ArrowField for TinyAsciiStr<N> {
     type Type = Self;
@@ -15,6 +19,8 @@ impl<const N: usize> ArrowField for TinyAsciiStr<N> {
     }
 }
 
+impl<const N: usize> ArrowEnableVecForType for TinyAsciiStr<N> {}
+
 impl<const N: usize> ArrowSerialize for TinyAsciiStr<N> {
     type ArrayBuilderType = FixedSizeBinaryBuilder;
 
@@ -22,8 +28,13 @@ impl<const N: usize> ArrowSerialize for TinyAsciiStr<N> {
         FixedSizeBinaryBuilder::new(N as i32)
     }
 
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        FixedSizeBinaryBuilder::with_capacity(capacity, N as i32)
+    }
+
     fn arrow_serialize(v: &Self::Type, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
-        array.append_value(v.as_bytes())?;
+        // Strings shorter than `N` are padded with trailing nulls.
+        array.append_value(v.all_bytes())?;
         Ok(())
     }
 }
@@ -32,6 +43,68 @@ impl<const N: usize> ArrowDeserialize for TinyAsciiStr<N> {
     type ArrayType = FixedSizeBinaryArray;
 
     fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
-        v.and_then(|bytes| TinyAsciiStr::try_from_utf8(bytes).ok())
+        v.and_then(|bytes| TinyAsciiStr::try_from_raw(bytes.try_into().ok()?).ok())
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        let Some(arr) = arr.as_any().downcast_ref::<FixedSizeBinaryArray>() else {
+            return Ok(());
+        };
+        for (index, bytes) in arr.iter().enumerate() {
+            let Some(bytes) = bytes else { continue };
+            let raw = bytes.try_into().map_err(|_| {
+                ArrowError::InvalidArgumentError(format!(
+                    "invalid TinyAsciiStr<{N}> at index {index}: expected {N} bytes, got {}",
+                    bytes.len()
+                ))
+            })?;
+            TinyAsciiStr::<N>::try_from_raw(raw).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!(
+                    "invalid TinyAsciiStr<{N}> at index {index}: {e} (bytes {bytes:?})"
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> ArrowSerialize for TinyAsciiStrUtf8<N> {
+    type ArrayBuilderType = StringBuilder;
+
+    fn new_array() -> Self::ArrayBuilderType {
+        StringBuilder::new()
+    }
+
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        StringBuilder::with_capacity(capacity, capacity * N)
+    }
+
+    fn arrow_serialize(v: &Self::Type, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
+        array.append_value(v.as_str());
+        Ok(())
+    }
+}
+
+impl<const N: usize> ArrowDeserialize for TinyAsciiStrUtf8<N> {
+    type ArrayType = StringArray;
+
+    fn arrow_deserialize(v: Option<&str>) -> Option<TinyAsciiStr<N>> {
+        v.and_then(|s| TinyAsciiStr::try_from_str(s).ok())
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), ArrowError> {
+        let Some(arr) = arr.as_any().downcast_ref::<StringArray>() else {
+            return Ok(());
+        };
+        for (index, s) in arr.iter().enumerate() {
+            let Some(s) = s else { continue };
+            TinyAsciiStr::<N>::try_from_str(s).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!(
+                    "invalid TinyAsciiStr<{N}> at index {index}: {e} ({s:?})"
+                ))
+            })?;
+        }
+        Ok(())
     }
 }
@@ -5,12 +5,12 @@ use std::sync::Arc;
 
 use crate::arrow_enable_vec_for_type;
 use crate::deserialize::ArrowDeserialize;
-use crate::field::ArrowField;
-use crate::serialize::ArrowSerialize;
+use crate::field::{ArrowExtension, ArrowField, UuidBinary, UuidString};
 use crate::serialize::PushNull;
-use arrow_array::builder::FixedSizeBinaryBuilder;
+use crate::serialize::{check_offset_overflow, ArrowSerialize};
+use arrow_array::builder::{FixedSizeBinaryBuilder, StringBuilder};
 use arrow_array::ArrayRef;
-use arrow_array::FixedSizeBinaryArray;
+use arrow_array::{Array, FixedSizeBinaryArray, StringArray};
 use arrow_schema::DataType;
 use uuid::Uuid;
 
@@ -24,11 +24,14 @@ impl ArrowField for Uuid {
 
     #[inline]
     fn field(name: &str) -> arrow_schema::Field {
-        arrow_schema::Field::new(name, Self::data_type(), Self::is_nullable())
-            .with_extension_type(arrow_schema::extension::Uuid)
+        <Self as ArrowExtension>::extension_field(name)
     }
 }
 
+impl ArrowExtension for Uuid {
+    const NAME: &'static str = "arrow.uuid";
+}
+
 impl ArrowDeserialize for Uuid {
     type ArrayType = FixedSizeBinaryArray;
 
@@ -36,6 +39,17 @@ impl ArrowDeserialize for Uuid {
     fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
         v.and_then(|t| Uuid::from_slice(t).ok())
     }
+
+    #[inline]
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        match arr.as_any().downcast_ref::<FixedSizeBinaryArray>() {
+            Some(arr) if arr.value_length() != 16 => Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                "invalid Uuid array: expected values of 16 bytes, got {}",
+                arr.value_length()
+            ))),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl ArrowSerialize for Uuid {
@@ -46,6 +60,11 @@ impl ArrowSerialize for Uuid {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        UuidBuilder(FixedSizeBinaryBuilder::with_capacity(capacity, 16))
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.0.append_value(v.as_bytes())
@@ -87,4 +106,84 @@ impl PushNull for UuidBuilder {
     }
 }
 
+impl ArrowSerialize for UuidString {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        StringBuilder::new()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        StringBuilder::with_capacity(capacity, capacity * uuid::fmt::Hyphenated::LENGTH)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Uuid, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), uuid::fmt::Hyphenated::LENGTH)?;
+        array.append_value(v.hyphenated().encode_lower(&mut Uuid::encode_buffer()));
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for UuidString {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Uuid> {
+        v.and_then(|t| Uuid::parse_str(t).ok())
+    }
+
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        let Some(arr) = arr.as_any().downcast_ref::<StringArray>() else {
+            return Ok(());
+        };
+        for (index, value) in arr.iter().enumerate() {
+            let Some(value) = value else { continue };
+            Uuid::parse_str(value).map_err(|e| {
+                arrow_schema::ArrowError::InvalidArgumentError(format!(
+                    "invalid Uuid at index {index}: {e} ({value:?})"
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for UuidBinary {
+    type ArrayBuilderType = UuidBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        <Uuid as ArrowSerialize>::new_array()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        <Uuid as ArrowSerialize>::new_array_with_capacity(capacity)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Uuid, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        <Uuid as ArrowSerialize>::arrow_serialize(v, array)
+    }
+}
+
+impl ArrowDeserialize for UuidBinary {
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Uuid> {
+        <Uuid as ArrowDeserialize>::arrow_deserialize(v)
+    }
+
+    #[inline]
+    fn validate_array(arr: &dyn Array) -> Result<(), arrow_schema::ArrowError> {
+        <Uuid as ArrowDeserialize>::validate_array(arr)
+    }
+}
+
 arrow_enable_vec_for_type!(Uuid);
+arrow_enable_vec_for_type!(UuidString);
+arrow_enable_vec_for_type!(UuidBinary);
@@ -1,11 +1,17 @@
 //! Implementation and traits for mapping rust types to Arrow types
 
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, Field};
+use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+use arrow_schema::{DataType, Field, Fields};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 
+use crate::json::{json_number_array, json_string_array};
+
 /// The default field name used when a specific name is not provided.
 pub const DEFAULT_FIELD_NAME: &str = "_item";
 
@@ -90,10 +96,11 @@ pub trait ArrowField {
     /// The [`DataType`]
     fn data_type() -> DataType;
 
+    /// Returns the [`arrow::datatypes::Field`] for this field.
+    ///
+    /// Wrappers and nested types build their child fields through this method, so metadata attached here, for
+    /// example by [`ArrowExtension::extension_field`], is kept wherever the type appears.
     #[inline]
-    #[doc(hidden)]
-    /// For internal use and not meant to be reimplemented.
-    /// returns the [`arrow::datatypes::Field`] for this field
     fn field(name: &str) -> Field {
         Field::new(name, Self::data_type(), Self::is_nullable())
     }
@@ -108,6 +115,60 @@ pub trait ArrowField {
     }
 }
 
+/// Implemented by [`ArrowField`] types that are stored as an Arrow extension type.
+///
+/// The storage type of the extension is the [`ArrowField::data_type`] of the implementing type. To attach the
+/// extension name and metadata to every field of the type, whether it is a struct field, a list element or a union
+/// variant, override [`ArrowField::field`] with [`ArrowExtension::extension_field`]:
+///
+/// ```rust
+/// # use arrow::datatypes::{DataType, Field};
+/// # use arrow_convert::field::{ArrowExtension, ArrowField};
+/// struct Point;
+///
+/// impl ArrowField for Point {
+///     type Type = Self;
+///
+///     fn data_type() -> DataType {
+///         DataType::FixedSizeBinary(16)
+///     }
+///
+///     fn field(name: &str) -> Field {
+///         <Self as ArrowExtension>::extension_field(name)
+///     }
+/// }
+///
+/// impl ArrowExtension for Point {
+///     const NAME: &'static str = "example.point";
+/// }
+///
+/// assert_eq!(Point::field("p").extension_type_name(), Some("example.point"));
+/// ```
+///
+/// Deserialization verifies the extension name where the source field is known, see
+/// [`check_extension_type`](crate::deserialize::check_extension_type).
+pub trait ArrowExtension: ArrowField {
+    /// The name of the extension type, stored under `ARROW:extension:name`.
+    const NAME: &'static str;
+
+    /// The serialized metadata of the extension type, stored under `ARROW:extension:metadata`.
+    #[inline]
+    fn metadata() -> Option<String> {
+        None
+    }
+
+    /// Returns a field named `name` with the storage type and the extension name and metadata of this type.
+    fn extension_field(name: &str) -> Field {
+        let mut field = Field::new(name, Self::data_type(), Self::is_nullable());
+        let metadata = field.metadata_mut();
+        metadata.insert(EXTENSION_TYPE_NAME_KEY.to_string(), Self::NAME.to_string());
+        if let Some(value) = Self::metadata() {
+            metadata.insert(EXTENSION_TYPE_METADATA_KEY.to_string(), value);
+        }
+        field
+    }
+}
+
 /// Enables the blanket implementations of [`Vec<T>`] as an Arrow field
 /// if `T` is an Arrow field.
 ///
@@ -156,6 +217,11 @@ where
         <T as ArrowField>::data_type()
     }
 
+    #[inline]
+    fn field(name: &str) -> Field {
+        <T as ArrowField>::field(name).with_nullable(true)
+    }
+
     #[inline]
     fn is_nullable() -> bool {
         true
@@ -196,6 +262,28 @@ impl<'a> ArrowField for &'a str {
     }
 }
 
+impl<'a> ArrowField for &'a [u8] {
+    type Type = &'a [u8];
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+}
+
+// Blanket implementation for slices of primitives
+impl<'a, T> ArrowField for &'a [T]
+where
+    T: ArrowField + ArrowNativeType + ArrowEnableVecForType,
+{
+    type Type = &'a [T];
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
 impl ArrowField for String {
     type Type = Self;
 
@@ -253,6 +341,47 @@ impl ArrowField for NaiveDate {
     }
 }
 
+/// Maps a temporal type like [`chrono::NaiveDateTime`] to the same Arrow type, clamping values outside the range of
+/// the Arrow type to its bounds instead of failing serialization.
+pub struct ClampTemporal<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for ClampTemporal<T>
+where
+    T: ArrowField<Type = T>,
+{
+    type Type = T;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <T as ArrowField>::data_type()
+    }
+}
+
+/// Maps an optional temporal type like `Option<chrono::NaiveDateTime>` to the same Arrow type, writing nulls for
+/// values outside the range of the Arrow type instead of failing serialization.
+pub struct NullTemporal<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for NullTemporal<T>
+where
+    T: ArrowField<Type = T>,
+{
+    type Type = Option<T>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <T as ArrowField>::data_type()
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
 // Treat both Buffer and ScalarBuffer<u8> the same
 impl ArrowField for Buffer {
     type Type = Self;
@@ -304,6 +433,240 @@ impl<const SIZE: i32> ArrowField for FixedSizeBinary<SIZE> {
     }
 }
 
+impl ArrowField for Ipv4Addr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::UInt32
+    }
+}
+
+/// Maps an [`Ipv4Addr`] to its 4 octets as `FixedSizeBinary(4)`, instead of the `UInt32` it's stored as by default.
+pub struct Ipv4Binary {}
+
+impl ArrowField for Ipv4Binary {
+    type Type = Ipv4Addr;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::FixedSizeBinary(4)
+    }
+}
+
+impl ArrowField for Ipv6Addr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::FixedSizeBinary(16)
+    }
+}
+
+/// IPv4 addresses are stored as IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`), so both kinds of addresses share a
+/// single `FixedSizeBinary(16)` representation. IPv4-mapped addresses are deserialized as [`IpAddr::V4`].
+impl ArrowField for IpAddr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::FixedSizeBinary(16)
+    }
+}
+
+/// Stored as a struct of the [`IpAddr`] (`ip`) and the port (`port`). The flow info and scope id of IPv6 socket
+/// addresses are not preserved.
+impl ArrowField for SocketAddr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Struct(Fields::from(vec![
+            <IpAddr as ArrowField>::field("ip"),
+            <u16 as ArrowField>::field("port"),
+        ]))
+    }
+}
+
+/// Stored as `Utf8`. Serializing a path that is not valid UTF-8 fails.
+impl ArrowField for PathBuf {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+/// Maps a [`tinystr::TinyAsciiStr`] to the `Utf8` Arrow type instead of `FixedSizeBinary(N)`.
+///
+/// Deserialization fails for strings longer than `N` bytes or containing non-ASCII characters.
+#[cfg(feature = "tinystr")]
+pub struct TinyAsciiStrUtf8<const N: usize> {}
+
+#[cfg(feature = "tinystr")]
+impl<const N: usize> ArrowField for TinyAsciiStrUtf8<N> {
+    type Type = tinystr::TinyAsciiStr<N>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+/// Maps a [`uuid::Uuid`] to the `Utf8` Arrow type as hyphenated text, e.g. for tables loaded from CSV or JSON.
+///
+/// Deserialization parses each string and fails with an error for values that are not valid UUIDs.
+#[cfg(feature = "uuid")]
+pub struct UuidString {}
+
+#[cfg(feature = "uuid")]
+impl ArrowField for UuidString {
+    type Type = uuid::Uuid;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+/// Maps a [`uuid::Uuid`] to a plain `FixedSizeBinary(16)` field without the `arrow.uuid` extension type.
+///
+/// [`uuid::Uuid`] tags its fields with the extension type, so nested columns such as lists or struct fields written by
+/// producers that don't attach the extension metadata fail the data type check. Use this type override to read and
+/// write such columns.
+#[cfg(feature = "uuid")]
+pub struct UuidBinary {}
+
+#[cfg(feature = "uuid")]
+impl ArrowField for UuidBinary {
+    type Type = uuid::Uuid;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::FixedSizeBinary(16)
+    }
+}
+
+/// Stores any `T: Serialize + DeserializeOwned` as JSON text in a `Utf8` field tagged with the canonical
+/// `arrow.json` extension type.
+///
+/// Values are parsed back into `T` on deserialization, and values that fail to parse are reported as errors.
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Json<T>(pub T);
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowField for Json<T> {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        <Self as ArrowExtension>::extension_field(name)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowExtension for Json<T> {
+    const NAME: &'static str = "arrow.json";
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowEnableVecForType for Json<T> {}
+
+/// Describes the coordinate reference system recorded in the metadata of a [`GeoArrow`] field.
+#[cfg(feature = "geo-types")]
+pub trait GeoCrs {
+    /// The CRS definition, such as an authority code like `EPSG:4326` or a PROJJSON document.
+    fn crs() -> Option<String>;
+
+    /// How [`GeoCrs::crs`] should be interpreted, e.g. `authority_code` or `projjson`.
+    fn crs_type() -> Option<&'static str> {
+        None
+    }
+}
+
+/// A [`GeoCrs`] that leaves the coordinate reference system unspecified.
+#[cfg(feature = "geo-types")]
+pub struct UnknownCrs;
+
+#[cfg(feature = "geo-types")]
+impl GeoCrs for UnknownCrs {
+    fn crs() -> Option<String> {
+        None
+    }
+}
+
+/// The WGS 84 longitude/latitude coordinate reference system (`OGC:CRS84`).
+#[cfg(feature = "geo-types")]
+pub struct Wgs84;
+
+#[cfg(feature = "geo-types")]
+impl GeoCrs for Wgs84 {
+    fn crs() -> Option<String> {
+        Some("OGC:CRS84".to_string())
+    }
+
+    fn crs_type() -> Option<&'static str> {
+        Some("authority_code")
+    }
+}
+
+/// Stores `geo_types::Coord<f64>` values in the interleaved GeoArrow coordinate layout, a
+/// `FixedSizeList<Float64, 2>` with an `xy` child.
+#[cfg(feature = "geo-types")]
+pub struct InterleavedCoord {}
+
+#[cfg(feature = "geo-types")]
+impl ArrowField for InterleavedCoord {
+    type Type = geo_types::Coord<f64>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::FixedSizeList(Arc::new(Field::new("xy", DataType::Float64, false)), 2)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl ArrowEnableVecForType for InterleavedCoord {}
+
+/// Stores `geo_types::Coord<f64>` values in the separated GeoArrow coordinate layout, a `Struct` with
+/// `x` and `y` `Float64` children.
+#[cfg(feature = "geo-types")]
+pub struct SeparatedCoord {}
+
+#[cfg(feature = "geo-types")]
+impl ArrowField for SeparatedCoord {
+    type Type = geo_types::Coord<f64>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Struct(Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, false),
+        ]))
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl ArrowEnableVecForType for SeparatedCoord {}
+
+/// Maps a `geo-types` geometry `T` to its native GeoArrow layout, tagged with the matching `geoarrow.*`
+/// extension type.
+///
+/// `C` is the [`GeoCrs`] written to the extension metadata and `L` the coordinate layout, either
+/// [`InterleavedCoord`] or [`SeparatedCoord`]. The geometry types themselves map to
+/// `GeoArrow<T, UnknownCrs, InterleavedCoord>`.
+#[cfg(feature = "geo-types")]
+pub struct GeoArrow<T, C = UnknownCrs, L = InterleavedCoord> {
+    d: std::marker::PhantomData<(T, C, L)>,
+}
+
 impl<const SIZE: usize> ArrowField for [u8; SIZE] {
     type Type = Self;
 
@@ -339,6 +702,49 @@ where
     }
 }
 
+// Blanket implementation for VecDeque, stored the same way as Vec.
+impl<T> ArrowField for VecDeque<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = VecDeque<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+// Blanket implementation for BTreeSet, stored as a list in the set's (sorted) order.
+impl<T> ArrowField for BTreeSet<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = BTreeSet<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+/// Blanket implementation for HashSet, stored as a list.
+///
+/// Elements are written in the set's iteration order, which depends on the hasher and is not
+/// guaranteed to be stable between runs or processes. Use a [`BTreeSet`] if the serialized
+/// order needs to be deterministic.
+impl<T, S> ArrowField for HashSet<T, S>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = HashSet<<T as ArrowField>::Type, S>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
 /// Represents the `LargeList` Arrow type.
 pub struct LargeVec<T> {
     d: std::marker::PhantomData<T>,
@@ -387,17 +793,300 @@ where
     }
 }
 
+/// Describes the shape of the tensors stored by a [`Tensor`] field.
+///
+/// The shape is stored in the `arrow.fixed_shape_tensor` extension metadata along with the optional dimension
+/// names and permutation. Fixed-size arrays such as `[[f32; 8]; 8]` describe their own shape, other tensor types
+/// use a shape type such as [`Shape2`] or a custom implementation.
+pub trait TensorShape {
+    /// The dimensions of the tensor, outermost first.
+    fn shape() -> Vec<usize>;
+
+    /// The names of the dimensions, stored as `dim_names` in the extension metadata.
+    #[inline]
+    fn dim_names() -> Option<Vec<String>> {
+        None
+    }
+
+    /// The permutation of the dimensions, stored as `permutation` in the extension metadata.
+    #[inline]
+    fn permutation() -> Option<Vec<usize>> {
+        None
+    }
+}
+
+// Macro to declare tensor shapes with fixed dimensions.
+macro_rules! impl_tensor_shape {
+    ($name:ident; $($dim:ident),+) => {
+        #[doc = concat!("A tensor shape with ", stringify!($name), " fixed dimensions, see [`TensorShape`].")]
+        pub struct $name<$(const $dim: usize),+> {}
+
+        impl<$(const $dim: usize),+> TensorShape for $name<$($dim),+> {
+            #[inline]
+            fn shape() -> Vec<usize> {
+                vec![$($dim),+]
+            }
+        }
+    };
+}
+
+impl_tensor_shape!(Shape1; D0);
+impl_tensor_shape!(Shape2; D0, D1);
+impl_tensor_shape!(Shape3; D0, D1, D2);
+impl_tensor_shape!(Shape4; D0, D1, D2, D3);
+
+/// Implemented by types whose values can be stored as the flattened elements of a [`Tensor`].
+///
+/// Elements are visited and rebuilt in row-major order of the tensor shape.
+pub trait TensorData: Sized {
+    /// The element type of the tensor.
+    type Element: ArrowField<Type = Self::Element>;
+
+    /// The shape of this value, outermost dimension first.
+    fn tensor_shape(&self) -> Vec<usize>;
+
+    /// Calls `f` on each element in row-major order, stopping at the first error.
+    fn try_for_each_element<F>(&self, f: &mut F) -> Result<(), arrow_schema::ArrowError>
+    where
+        F: FnMut(&Self::Element) -> Result<(), arrow_schema::ArrowError>;
+
+    /// Rebuilds a value of the given `shape` from its elements in row-major order.
+    fn from_tensor_elements<I>(shape: &[usize], elements: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = Self::Element>;
+}
+
+// Macro to implement the tensor traits for scalar elements, which are tensors of rank 0.
+macro_rules! impl_tensor_scalar {
+    ($t:ty) => {
+        impl TensorShape for $t {
+            #[inline]
+            fn shape() -> Vec<usize> {
+                vec![]
+            }
+        }
+
+        impl TensorData for $t {
+            type Element = $t;
+
+            #[inline]
+            fn tensor_shape(&self) -> Vec<usize> {
+                vec![]
+            }
+
+            #[inline]
+            fn try_for_each_element<F>(&self, f: &mut F) -> Result<(), arrow_schema::ArrowError>
+            where
+                F: FnMut(&Self::Element) -> Result<(), arrow_schema::ArrowError>,
+            {
+                f(self)
+            }
+
+            #[inline]
+            fn from_tensor_elements<I>(_shape: &[usize], elements: &mut I) -> Option<Self>
+            where
+                I: Iterator<Item = Self::Element>,
+            {
+                elements.next()
+            }
+        }
+    };
+}
+
+impl_tensor_scalar!(bool);
+impl_tensor_scalar!(u8);
+impl_tensor_scalar!(u16);
+impl_tensor_scalar!(u32);
+impl_tensor_scalar!(u64);
+impl_tensor_scalar!(i8);
+impl_tensor_scalar!(i16);
+impl_tensor_scalar!(i32);
+impl_tensor_scalar!(i64);
+impl_tensor_scalar!(half::f16);
+impl_tensor_scalar!(f32);
+impl_tensor_scalar!(f64);
+
+impl<T: TensorShape, const N: usize> TensorShape for [T; N] {
+    #[inline]
+    fn shape() -> Vec<usize> {
+        std::iter::once(N).chain(T::shape()).collect()
+    }
+}
+
+impl<T: TensorData + TensorShape, const N: usize> TensorData for [T; N] {
+    type Element = T::Element;
+
+    #[inline]
+    fn tensor_shape(&self) -> Vec<usize> {
+        <Self as TensorShape>::shape()
+    }
+
+    #[inline]
+    fn try_for_each_element<F>(&self, f: &mut F) -> Result<(), arrow_schema::ArrowError>
+    where
+        F: FnMut(&Self::Element) -> Result<(), arrow_schema::ArrowError>,
+    {
+        self.iter().try_for_each(|v| v.try_for_each_element(f))
+    }
+
+    #[inline]
+    fn from_tensor_elements<I>(shape: &[usize], elements: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = Self::Element>,
+    {
+        let inner = shape.get(1..)?;
+        (0..N)
+            .map(|_| T::from_tensor_elements(inner, elements))
+            .collect::<Option<Vec<_>>>()?
+            .try_into()
+            .ok()
+    }
+}
+
+/// Maps a tensor `T` of shape `S` to the canonical `arrow.fixed_shape_tensor` extension type.
+///
+/// The elements are stored flattened in row-major order in a `FixedSizeList` whose size is the product of the
+/// shape. `S` defaults to `T` for types that describe their own shape, so `Tensor<[[f32; 8]; 8]>` stores 8x8
+/// matrices. With the `ndarray` feature, `Tensor<Array2<f32>, Shape2<8, 8>>` stores `ndarray` arrays, and
+/// serializing an array of a different shape fails.
+pub struct Tensor<T, S = T> {
+    d: std::marker::PhantomData<(T, S)>,
+}
+
+impl<T, S> ArrowField for Tensor<T, S>
+where
+    T: TensorData,
+    S: TensorShape,
+{
+    type Type = T;
+
+    #[inline]
+    fn data_type() -> DataType {
+        let field = <T::Element as ArrowField>::field(DEFAULT_FIELD_NAME);
+        DataType::FixedSizeList(Arc::new(field), S::shape().iter().product::<usize>() as i32)
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        <Self as ArrowExtension>::extension_field(name)
+    }
+}
+
+impl<T, S> ArrowExtension for Tensor<T, S>
+where
+    T: TensorData,
+    S: TensorShape,
+{
+    const NAME: &'static str = "arrow.fixed_shape_tensor";
+
+    fn metadata() -> Option<String> {
+        let mut metadata = format!(r#"{{"shape":{}"#, json_number_array(&S::shape()));
+        if let Some(dim_names) = S::dim_names() {
+            metadata.push_str(&format!(r#","dim_names":{}"#, json_string_array(&dim_names)));
+        }
+        if let Some(permutation) = S::permutation() {
+            metadata.push_str(&format!(
+                r#","permutation":{}"#,
+                json_number_array(&permutation)
+            ));
+        }
+        metadata.push('}');
+        Some(metadata)
+    }
+}
+
+impl<T: TensorData, S: TensorShape> ArrowEnableVecForType for Tensor<T, S> {}
+
+/// Maps an `ndarray` array to the canonical `arrow.variable_shape_tensor` extension type, for tensors whose shape
+/// differs between values.
+///
+/// Each value is stored as a struct of its flattened elements in row-major order (`data`) and its shape (`shape`),
+/// where `NDIM` is the number of dimensions of every tensor. `T` is typically [`ndarray::ArrayD`] or an array with a
+/// fixed number of dimensions such as [`ndarray::Array2`].
+#[cfg(feature = "ndarray")]
+pub struct VariableShapeTensor<T, const NDIM: i32> {
+    d: std::marker::PhantomData<T>,
+}
+
+/// Stores a matrix in row-major order, instead of the column-major order matrices are stored in by default.
+///
+/// With the `glam` feature, `RowMajor<glam::Mat3>` stores a `glam::Mat3` as a flat `FixedSizeList` of its 9 scalars
+/// in row-major order. The data type is the same as the one of the default column-major layout, so readers can't tell
+/// the two layouts apart from the schema.
+#[cfg(feature = "glam")]
+pub struct RowMajor<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "glam")]
+impl<T> ArrowEnableVecForType for RowMajor<T> {}
+
+/// Stores a vector as a struct with one field per component (`x`, `y`, `z` and `w`), instead of the `FixedSizeList`
+/// vectors are stored as by default.
+///
+/// With the `glam` feature, `StructVec<glam::Vec3>` stores a `glam::Vec3` as `Struct<x: Float32, y: Float32, z: Float32>`.
+#[cfg(feature = "glam")]
+pub struct StructVec<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "glam")]
+impl<T> ArrowEnableVecForType for StructVec<T> {}
+
+// Macro to facilitate implementation for tuples. Tuples are mapped to anonymous structs whose
+// fields are named `field_0..field_n`, consistent with the naming used for tuple structs.
+macro_rules! impl_tuple_type {
+    ($($name:ident: $idx:tt),+) => {
+        impl<$($name),+> ArrowField for ($($name,)+)
+        where
+            $($name: ArrowField,)+
+        {
+            type Type = ($(<$name as ArrowField>::Type,)+);
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Struct(Fields::from(vec![
+                    $(<$name as ArrowField>::field(concat!("field_", $idx)),)+
+                ]))
+            }
+        }
+
+        impl<$($name),+> ArrowEnableVecForType for ($($name,)+) where $($name: ArrowField,)+ {}
+    };
+}
+
+impl_tuple_type!(A: 0);
+impl_tuple_type!(A: 0, B: 1);
+impl_tuple_type!(A: 0, B: 1, C: 2);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+impl_tuple_type!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
 arrow_enable_vec_for_type!(String);
 arrow_enable_vec_for_type!(LargeString);
 arrow_enable_vec_for_type!(bool);
 arrow_enable_vec_for_type!(NaiveDateTime);
 arrow_enable_vec_for_type!(DateTime<Utc>);
 arrow_enable_vec_for_type!(NaiveDate);
+impl<T: ArrowField<Type = T>> ArrowEnableVecForType for ClampTemporal<T> {}
+impl<T: ArrowField<Type = T>> ArrowEnableVecForType for NullTemporal<T> {}
 arrow_enable_vec_for_type!(Vec<u8>);
 arrow_enable_vec_for_type!(Buffer);
 arrow_enable_vec_for_type!(ScalarBuffer<u8>);
 arrow_enable_vec_for_type!(LargeBinary);
 impl<const SIZE: i32> ArrowEnableVecForType for FixedSizeBinary<SIZE> {}
+arrow_enable_vec_for_type!(Ipv4Addr);
+arrow_enable_vec_for_type!(Ipv4Binary);
+arrow_enable_vec_for_type!(Ipv6Addr);
+arrow_enable_vec_for_type!(IpAddr);
+arrow_enable_vec_for_type!(SocketAddr);
+arrow_enable_vec_for_type!(PathBuf);
 impl<const PRECISION: u8, const SCALE: i8> ArrowEnableVecForType for I128<PRECISION, SCALE> {}
 
 // Blanket implementation for Vec<Option<T>> if vectors are enabled for T
@@ -405,6 +1094,11 @@ impl<T> ArrowEnableVecForType for Option<T> where T: ArrowField + ArrowEnableVec
 
 // Blanket implementation for Vec<Vec<T>> and Vec<Buffer<T>> if vectors or buffers are enabled for T
 impl<T> ArrowEnableVecForType for Vec<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for VecDeque<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for BTreeSet<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T, S> ArrowEnableVecForType for HashSet<T, S> where T: ArrowField + ArrowEnableVecForType {}
 impl<T> ArrowEnableVecForType for ScalarBuffer<T> where T: ArrowField + ArrowEnableVecForType + ArrowNativeType {}
 impl<T> ArrowEnableVecForType for LargeVec<T> where T: ArrowField + ArrowEnableVecForType {}
 impl<T, const SIZE: i32> ArrowEnableVecForType for FixedSizeVec<T, SIZE> where T: ArrowField + ArrowEnableVecForType {}
+impl<T, const SIZE: usize> ArrowEnableVecForType for [T; SIZE] where T: ArrowField + ArrowEnableVecForType {}
+impl<const SIZE: usize> ArrowEnableVecForType for [u8; SIZE] {}
@@ -0,0 +1,171 @@
+//! Helpers to read and write the JSON metadata of extension types.
+
+use std::fmt::Display;
+
+/// Quotes and escapes `s` as a JSON string.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Writes `values` as a JSON array of numbers.
+pub(crate) fn json_number_array<T: Display>(values: &[T]) -> String {
+    let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+/// Writes `values` as a JSON array of strings.
+pub(crate) fn json_string_array<S: AsRef<str>>(values: &[S]) -> String {
+    let values = values.iter().map(|v| json_string(v.as_ref())).collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+/// Reads the array of unsigned integers stored under `key` in the JSON object `json`, skipping the other members of
+/// the object. Returns `Ok(None)` if `key` is absent and `Err` if `json` isn't an object or the value isn't such an
+/// array.
+pub(crate) fn parse_json_number_array(json: &str, key: &str) -> Result<Option<Vec<usize>>, String> {
+    let mut parser = Parser { json, pos: 0 };
+    let mut found = None;
+    parser.expect('{')?;
+    if !parser.eat('}') {
+        loop {
+            let name = parser.string()?;
+            parser.expect(':')?;
+            if name == key {
+                found = Some(parser.number_array()?);
+            } else {
+                parser.skip_value()?;
+            }
+            if parser.eat('}') {
+                break;
+            }
+            parser.expect(',')?;
+        }
+    }
+    parser.skip_whitespace();
+    match parser.pos == json.len() {
+        true => Ok(found),
+        false => Err(parser.error("end of input")),
+    }
+}
+
+// Minimal JSON reader, sufficient for the flat objects used as extension metadata.
+struct Parser<'a> {
+    json: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, expected: &str) -> String {
+        format!("expected {expected} at offset {} of {:?}", self.pos, self.json)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.json[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.json[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += c.len_utf8();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.eat(c) {
+            true => Ok(()),
+            false => Err(self.error(&format!("'{c}'"))),
+        }
+    }
+
+    // Returns the raw contents of a string, without unescaping.
+    fn string(&mut self) -> Result<&'a str, String> {
+        self.expect('"')?;
+        let start = self.pos;
+        let mut chars = self.json[start..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos = start + i + 1;
+                    return Ok(&self.json[start..start + i]);
+                }
+                '\\' => {
+                    chars.next();
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("'\"'"))
+    }
+
+    // Consumes a number or a literal such as `true` or `null`.
+    fn scalar(&mut self) -> Result<&'a str, String> {
+        self.skip_whitespace();
+        let rest = &self.json[self.pos..];
+        let len = rest
+            .find(|c: char| c == ',' || c == ']' || c == '}' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("a value"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn number_array(&mut self) -> Result<Vec<usize>, String> {
+        let mut values = vec![];
+        self.expect('[')?;
+        if self.eat(']') {
+            return Ok(values);
+        }
+        loop {
+            let value = self.scalar()?;
+            values.push(value.parse().map_err(|_| self.error("an unsigned integer"))?);
+            if self.eat(']') {
+                return Ok(values);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn skip_value(&mut self) -> Result<(), String> {
+        let (open, close) = match self.peek() {
+            Some('"') => return self.string().map(|_| ()),
+            Some('[') => ('[', ']'),
+            Some('{') => ('{', '}'),
+            _ => return self.scalar().map(|_| ()),
+        };
+        self.expect(open)?;
+        if self.eat(close) {
+            return Ok(());
+        }
+        loop {
+            if open == '{' {
+                self.string()?;
+                self.expect(':')?;
+            }
+            self.skip_value()?;
+            if self.eat(close) {
+                return Ok(());
+            }
+            self.expect(',')?;
+        }
+    }
+}
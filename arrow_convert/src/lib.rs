@@ -19,3 +19,6 @@ pub use arrow_convert_derive::{ArrowDeserialize, ArrowField, ArrowSerialize};
 struct ReadmeDoctests;
 
 mod features;
+mod json;
+#[cfg(feature = "rayon")]
+mod parallel;
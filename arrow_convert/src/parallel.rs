@@ -0,0 +1,9 @@
+//! Helpers shared by parallel serialization and deserialization.
+
+/// Minimum number of elements per chunk when the chunk size is chosen automatically.
+const MIN_CHUNK_SIZE: usize = 4096;
+
+// Chooses a chunk size giving each rayon thread a few chunks to balance uneven work.
+pub(crate) fn default_chunk_size(len: usize) -> usize {
+    (len / (rayon::current_num_threads() * 4)).max(MIN_CHUNK_SIZE)
+}
@@ -0,0 +1,108 @@
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::ArrowError;
+
+use super::ArrowSerialize;
+use crate::field::ArrowField;
+
+/// Serializes a slice into one or more arrays, starting a new array whenever a 32-bit offset would overflow.
+///
+/// Columns such as `String`, `Vec<u8>` and `Vec<T>` use 32-bit offsets, so a single array can hold at most
+/// `i32::MAX` bytes of string data or list values. Instead of failing late with
+/// [`ArrowError::OffsetOverflowError`], the values that fit are emitted as one array and serialization continues
+/// into a new one. A single value that doesn't fit on its own still returns the error.
+///
+/// Since a nested value can overflow after some of its children were already appended, the values of a completed
+/// array are serialized a second time into a fresh builder. This only happens when an array is split.
+pub fn arrow_serialize_chunked<A, T>(values: &[A]) -> Result<Vec<ArrayRef>, ArrowError>
+where
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+{
+    let mut arrays = vec![];
+    let mut start = 0;
+    let mut builder = <T as ArrowSerialize>::new_array_with_capacity(values.len());
+    let mut index = 0;
+
+    while index < values.len() {
+        match <T as ArrowSerialize>::arrow_serialize(&values[index], &mut builder) {
+            Ok(()) => index += 1,
+            Err(ArrowError::OffsetOverflowError(_)) if index > start => {
+                // the builder may hold a partially written value, so the values that fit are serialized again
+                builder = <T as ArrowSerialize>::new_array_with_capacity(index - start);
+                for v in &values[start..index] {
+                    <T as ArrowSerialize>::arrow_serialize(v, &mut builder)?;
+                }
+                arrays.push(<T as ArrowSerialize>::try_finish(&mut builder)?);
+
+                start = index;
+                builder = <T as ArrowSerialize>::new_array_with_capacity(values.len() - start);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    if start < values.len() || arrays.is_empty() {
+        arrays.push(<T as ArrowSerialize>::try_finish(&mut builder)?);
+    }
+    Ok(arrays)
+}
+
+/// Top-level API to serialize to Arrow, splitting the output on offset overflow, see [`arrow_serialize_chunked`].
+pub trait TryIntoArrowChunked<ArrowArray, Element>
+where
+    Element: 'static,
+{
+    /// Convert a slice into one or more `arrow::Array`s
+    fn try_into_arrow_chunked(&self) -> Result<Vec<ArrowArray>, ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static;
+
+    /// Same as `try_into_arrow_chunked` except can coerce the conversion to a specific Arrow type.
+    fn try_into_arrow_chunked_as_type<ArrowType>(&self) -> Result<Vec<ArrowArray>, ArrowError>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static;
+}
+
+impl<Element> TryIntoArrowChunked<ArrayRef, Element> for [Element]
+where
+    Element: 'static,
+{
+    fn try_into_arrow_chunked(&self) -> Result<Vec<ArrayRef>, ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        arrow_serialize_chunked::<Element, Element>(self)
+    }
+
+    fn try_into_arrow_chunked_as_type<Field>(&self) -> Result<Vec<ArrayRef>, ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        arrow_serialize_chunked::<Element, Field>(self)
+    }
+}
+
+impl<Element> TryIntoArrowChunked<RecordBatch, Element> for [Element]
+where
+    Element: 'static,
+{
+    fn try_into_arrow_chunked(&self) -> Result<Vec<RecordBatch>, ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        arrow_serialize_chunked::<Element, Element>(self)?
+            .into_iter()
+            .map(|array| RecordBatch::try_from_iter([("record_batch_item", array)]))
+            .collect()
+    }
+
+    fn try_into_arrow_chunked_as_type<Field>(&self) -> Result<Vec<RecordBatch>, ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        arrow_serialize_chunked::<Element, Field>(self)?
+            .into_iter()
+            .map(|array| RecordBatch::try_from_iter([("record_batch_item", array)]))
+            .collect()
+    }
+}
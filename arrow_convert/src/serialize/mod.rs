@@ -4,11 +4,25 @@ use arrow_array::{builder::*, types, *};
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
 use arrow_schema::DataType;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 mod push_null;
 pub use push_null::*;
 
+mod tuple;
+pub use tuple::*;
+
+mod chunked;
+pub use chunked::*;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+
 use crate::field::*;
 
 /// Trait that is implemented by all types that are serializable to Arrow.
@@ -24,6 +38,33 @@ pub trait ArrowSerialize: ArrowField {
     /// Create a new mutable array
     fn new_array() -> Self::ArrayBuilderType;
 
+    /// Create a new mutable array with room for at least `capacity` values.
+    ///
+    /// The default implementation ignores the hint and calls [`ArrowSerialize::new_array`].
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let _ = capacity;
+        Self::new_array()
+    }
+
+    /// Create a new mutable array for the declared `field` with room for at least `capacity` values.
+    ///
+    /// List-like builders take their element field from `field`, so that list element names and metadata
+    /// declared on a struct field are produced by the builder as-is. The default implementation ignores `field`.
+    #[inline]
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        let _ = field;
+        Self::new_array_with_capacity(capacity)
+    }
+
+    /// Finish the mutable array, returning an error instead of panicking if the array can't be built.
+    ///
+    /// The default implementation calls [`ArrayBuilder::finish`].
+    #[inline]
+    fn try_finish(array: &mut Self::ArrayBuilderType) -> Result<ArrayRef, arrow_schema::ArrowError> {
+        Ok(array.finish())
+    }
+
     /// Serialize this field to arrow
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
@@ -42,6 +83,11 @@ macro_rules! impl_numeric_type {
                 Self::ArrayBuilderType::default()
             }
 
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::with_capacity(capacity)
+            }
+
             #[inline]
             fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
                 array.append_option(Some(*v));
@@ -64,6 +110,21 @@ where
         <T as ArrowSerialize>::new_array()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        <T as ArrowSerialize>::new_array_with_capacity(capacity)
+    }
+
+    #[inline]
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        <T as ArrowSerialize>::new_array_for_field(field, capacity)
+    }
+
+    #[inline]
+    fn try_finish(array: &mut Self::ArrayBuilderType) -> Result<ArrayRef, arrow_schema::ArrowError> {
+        <T as ArrowSerialize>::try_finish(array)
+    }
+
     #[inline]
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
@@ -99,6 +160,11 @@ impl<const PRECISION: u8, const SCALE: i8> ArrowSerialize for I128<PRECISION, SC
         Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity).with_data_type(<Self as ArrowField>::data_type())
+    }
+
     #[inline]
     fn arrow_serialize(v: &i128, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_option(Some(*v));
@@ -114,8 +180,14 @@ impl ArrowSerialize for &str {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
@@ -129,8 +201,14 @@ impl ArrowSerialize for String {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
@@ -144,6 +222,11 @@ impl ArrowSerialize for LargeString {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &String, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_option(Some(v));
@@ -159,6 +242,11 @@ impl ArrowSerialize for bool {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_value(*v);
@@ -166,23 +254,127 @@ impl ArrowSerialize for bool {
     }
 }
 
-impl ArrowSerialize for NaiveDateTime {
-    type ArrayBuilderType = TimestampNanosecondBuilder;
+/// Conversion of temporal values to the primitive Arrow representation.
+///
+/// Values that can't be represented are a serialization error by default. [`ClampTemporal`] and [`NullTemporal`]
+/// can be used with `#[arrow_field(type = "...")]` to clamp them or write nulls instead.
+pub trait ArrowTemporal: ArrowField<Type = Self> + Sized + 'static {
+    /// The primitive Arrow type this value is stored as
+    type ArrowType: types::ArrowPrimitiveType;
+
+    /// Converts the value, returning `None` if it's outside the range of the Arrow type.
+    fn to_native(v: &Self) -> Option<<Self::ArrowType as types::ArrowPrimitiveType>::Native>;
+
+    /// Converts the value, saturating at the bounds of the Arrow type.
+    fn to_native_clamped(v: &Self) -> <Self::ArrowType as types::ArrowPrimitiveType>::Native;
+}
+
+impl ArrowTemporal for NaiveDateTime {
+    type ArrowType = types::TimestampNanosecondType;
 
     #[inline]
-    fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    fn to_native(v: &Self) -> Option<i64> {
+        v.and_utc().timestamp_nanos_opt()
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
-        array.append_option(v.and_utc().timestamp_nanos_opt());
-        Ok(())
+    fn to_native_clamped(v: &Self) -> i64 {
+        <DateTime<Utc> as ArrowTemporal>::to_native_clamped(&v.and_utc())
+    }
+}
+
+impl ArrowTemporal for DateTime<Utc> {
+    type ArrowType = types::TimestampNanosecondType;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i64> {
+        v.timestamp_nanos_opt()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i64 {
+        match v.timestamp_nanos_opt() {
+            Some(ns) => ns,
+            None if v.timestamp() < 0 => i64::MIN,
+            None => i64::MAX,
+        }
     }
 }
 
-impl ArrowSerialize for DateTime<Utc> {
-    type ArrayBuilderType = TimestampNanosecondBuilder;
+impl ArrowTemporal for NaiveDate {
+    type ArrowType = types::Date32Type;
+
+    #[inline]
+    fn to_native(v: &Self) -> Option<i32> {
+        i32::try_from(naive_date_to_days(v)).ok()
+    }
+
+    #[inline]
+    fn to_native_clamped(v: &Self) -> i32 {
+        naive_date_to_days(v).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+}
+
+// days since the unix epoch
+#[inline]
+fn naive_date_to_days(v: &NaiveDate) -> i64 {
+    chrono::Datelike::num_days_from_ce(v) as i64 - arrow_array::temporal_conversions::UNIX_EPOCH_DAY
+}
+
+// Serializes a temporal value, failing if it can't be represented by its Arrow type.
+#[inline]
+pub(crate) fn arrow_serialize_temporal<T: ArrowTemporal + std::fmt::Display>(
+    v: &T,
+    array: &mut PrimitiveBuilder<T::ArrowType>,
+) -> Result<(), arrow_schema::ArrowError> {
+    match T::to_native(v) {
+        Some(native) => {
+            array.append_value(native);
+            Ok(())
+        }
+        None => Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+            "{v} is out of range for {}",
+            <T as ArrowField>::data_type()
+        ))),
+    }
+}
+
+// Implements `ArrowSerialize` for a type implementing `ArrowTemporal`, failing on out of range values.
+macro_rules! impl_temporal_type {
+    ($temporal_type:ty) => {
+        impl $crate::serialize::ArrowSerialize for $temporal_type {
+            type ArrayBuilderType =
+                arrow_array::builder::PrimitiveBuilder<<Self as $crate::serialize::ArrowTemporal>::ArrowType>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default().with_data_type(<Self as $crate::field::ArrowField>::data_type())
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::with_capacity(capacity)
+                    .with_data_type(<Self as $crate::field::ArrowField>::data_type())
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+                $crate::serialize::arrow_serialize_temporal(v, array)
+            }
+        }
+    };
+}
+
+// re-exported for the `time` and `jiff` features
+#[cfg(any(feature = "time", feature = "jiff"))]
+pub(crate) use impl_temporal_type;
+
+impl_temporal_type!(NaiveDateTime);
+impl_temporal_type!(DateTime<Utc>);
+impl_temporal_type!(NaiveDate);
+
+impl<T: ArrowTemporal> ArrowSerialize for ClampTemporal<T> {
+    type ArrayBuilderType = PrimitiveBuilder<T::ArrowType>;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -190,14 +382,19 @@ impl ArrowSerialize for DateTime<Utc> {
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
-        array.append_option(v.timestamp_nanos_opt());
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity).with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &T, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.append_value(T::to_native_clamped(v));
         Ok(())
     }
 }
 
-impl ArrowSerialize for NaiveDate {
-    type ArrayBuilderType = Date32Builder;
+impl<T: ArrowTemporal> ArrowSerialize for NullTemporal<T> {
+    type ArrayBuilderType = PrimitiveBuilder<T::ArrowType>;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -205,10 +402,13 @@ impl ArrowSerialize for NaiveDate {
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
-        array.append_option(Some(
-            chrono::Datelike::num_days_from_ce(v) - arrow_array::temporal_conversions::UNIX_EPOCH_DAY as i32,
-        ));
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity).with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Option<T>, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.append_option(v.as_ref().and_then(T::to_native));
         Ok(())
     }
 }
@@ -222,8 +422,14 @@ impl ArrowSerialize for Buffer {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
         array.append_option(Some(v.as_slice()));
         Ok(())
     }
@@ -236,8 +442,14 @@ impl ArrowSerialize for ScalarBuffer<u8> {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
@@ -251,8 +463,14 @@ impl ArrowSerialize for Vec<u8> {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        check_offset_overflow(array.values_slice().len(), v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
@@ -266,6 +484,11 @@ impl ArrowSerialize for LargeBinary {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_option(Some(v));
@@ -281,6 +504,11 @@ impl<const SIZE: i32> ArrowSerialize for FixedSizeBinary<SIZE> {
         Self::ArrayBuilderType::new(SIZE)
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, SIZE)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_value(v)
@@ -295,12 +523,154 @@ impl<const SIZE: usize> ArrowSerialize for [u8; SIZE] {
         Self::ArrayBuilderType::new(SIZE as i32)
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, SIZE as i32)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
         array.append_value(v)
     }
 }
 
+impl ArrowSerialize for Ipv4Addr {
+    type ArrayBuilderType = UInt32Builder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.append_value(u32::from(*v));
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for Ipv4Binary {
+    type ArrayBuilderType = FixedSizeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(4)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 4)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Ipv4Addr, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.append_value(v.octets())
+    }
+}
+
+impl ArrowSerialize for Ipv6Addr {
+    type ArrayBuilderType = FixedSizeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(16)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 16)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.append_value(v.octets())
+    }
+}
+
+impl ArrowSerialize for IpAddr {
+    type ArrayBuilderType = FixedSizeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(16)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 16)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        let v6 = match v {
+            IpAddr::V4(v4) => v4.to_ipv6_mapped(),
+            IpAddr::V6(v6) => *v6,
+        };
+        array.append_value(v6.octets())
+    }
+}
+
+impl ArrowSerialize for SocketAddr {
+    type ArrayBuilderType = Tuple2Builder<IpAddr, u16>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::new_array_with_capacity(0)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let DataType::Struct(fields) = <Self as ArrowField>::data_type() else {
+            unreachable!("socket addresses are always mapped to structs")
+        };
+        Tuple2Builder::try_with_fields(fields, capacity).unwrap_or_else(|e| unreachable!("{e}"))
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::Struct(fields) => Tuple2Builder::try_with_fields(fields.clone(), capacity)
+                .unwrap_or_else(|_| Self::new_array_with_capacity(capacity)),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        array.try_push(&(v.ip(), v.port()))
+    }
+}
+
+impl ArrowSerialize for PathBuf {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(capacity, 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        let Some(path) = v.to_str() else {
+            return Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                "path {v:?} is not valid UTF-8"
+            )));
+        };
+        check_offset_overflow(array.values_slice().len(), path.len())?;
+        array.append_value(path);
+        Ok(())
+    }
+}
+
 // Blanket implementation for Buffer
 impl<T> ArrowSerialize for ScalarBuffer<T>
 where
@@ -314,12 +684,29 @@ where
         ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::with_capacity(<T as ArrowSerialize>::new_array(), capacity).with_field(field)
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::List(child) => {
+                ListBuilder::with_capacity(<T as ArrowSerialize>::new_array_for_field(child, 0), capacity)
+                    .with_field(child.clone())
+            }
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
     #[inline]
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::ArrayBuilderType,
     ) -> Result<(), arrow_schema::ArrowError> {
         let values = array.values();
+        check_offset_overflow(values.len(), v.len())?;
         for i in v.iter() {
             <T as ArrowSerialize>::arrow_serialize(i, values)?;
         }
@@ -332,7 +719,6 @@ where
 impl<T> ArrowSerialize for Vec<T>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -342,11 +728,28 @@ where
         ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::with_capacity(<T as ArrowSerialize>::new_array(), capacity).with_field(field)
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::List(child) => {
+                ListBuilder::with_capacity(<T as ArrowSerialize>::new_array_for_field(child, 0), capacity)
+                    .with_field(child.clone())
+            }
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::ArrayBuilderType,
     ) -> Result<(), arrow_schema::ArrowError> {
         let values = array.values();
+        check_offset_overflow(values.len(), v.len())?;
         for i in v.iter() {
             <T as ArrowSerialize>::arrow_serialize(i, values)?;
         }
@@ -355,10 +758,53 @@ where
     }
 }
 
+// Blanket implementations for other collections, reusing the Vec list builder
+macro_rules! impl_list_collection {
+    ($collection:ident<T $(, $param:ident)*>) => {
+        impl<T $(, $param)*> ArrowSerialize for $collection<T $(, $param)*>
+        where
+            T: ArrowSerialize + ArrowEnableVecForType + 'static,
+        {
+            type ArrayBuilderType = <Vec<T> as ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <Vec<T> as ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                <Vec<T> as ArrowSerialize>::new_array_with_capacity(capacity)
+            }
+
+            #[inline]
+            fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+                <Vec<T> as ArrowSerialize>::new_array_for_field(field, capacity)
+            }
+
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::ArrayBuilderType,
+            ) -> Result<(), arrow_schema::ArrowError> {
+                let values = array.values();
+                check_offset_overflow(values.len(), v.len())?;
+                for i in v.iter() {
+                    <T as ArrowSerialize>::arrow_serialize(i, values)?;
+                }
+                array.append(true);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_list_collection!(VecDeque<T>);
+impl_list_collection!(BTreeSet<T>);
+impl_list_collection!(HashSet<T, S>);
+
 impl<T> ArrowSerialize for LargeVec<T>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = LargeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -368,6 +814,22 @@ where
         Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array()).with_field(field)
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        Self::ArrayBuilderType::with_capacity(<T as ArrowSerialize>::new_array(), capacity).with_field(field)
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::LargeList(child) => {
+                LargeListBuilder::with_capacity(<T as ArrowSerialize>::new_array_for_field(child, 0), capacity)
+                    .with_field(child.clone())
+            }
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::ArrayBuilderType,
@@ -384,7 +846,6 @@ where
 impl<T, const SIZE: i32> ArrowSerialize for FixedSizeVec<T, SIZE>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -394,6 +855,28 @@ where
             .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(
+            <T as ArrowSerialize>::new_array_with_capacity(capacity * SIZE as usize),
+            SIZE,
+            capacity,
+        )
+        .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::FixedSizeList(child, _) => Self::ArrayBuilderType::with_capacity(
+                <T as ArrowSerialize>::new_array_for_field(child, capacity * SIZE as usize),
+                SIZE,
+                capacity,
+            )
+            .with_field(child.clone()),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::ArrayBuilderType,
@@ -410,7 +893,6 @@ where
 impl<T, const SIZE: usize> ArrowSerialize for [T; SIZE]
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -420,6 +902,28 @@ where
             .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
     }
 
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(
+            <T as ArrowSerialize>::new_array_with_capacity(capacity * SIZE),
+            SIZE as i32,
+            capacity,
+        )
+        .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::FixedSizeList(child, _) => Self::ArrayBuilderType::with_capacity(
+                <T as ArrowSerialize>::new_array_for_field(child, capacity * SIZE),
+                SIZE as i32,
+                capacity,
+            )
+            .with_field(child.clone()),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::ArrayBuilderType,
@@ -433,17 +937,95 @@ where
     }
 }
 
+impl<T, S> ArrowSerialize for Tensor<T, S>
+where
+    T: TensorData + 'static,
+    T::Element: ArrowSerialize + 'static,
+    S: TensorShape + 'static,
+{
+    type ArrayBuilderType = FixedSizeListBuilder<<T::Element as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::new_array_with_capacity(0)
+    }
+
+    #[inline]
+    fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+        let size = S::shape().iter().product::<usize>();
+        Self::ArrayBuilderType::with_capacity(
+            <T::Element as ArrowSerialize>::new_array_with_capacity(capacity * size),
+            size as i32,
+            capacity,
+        )
+        .with_field(<T::Element as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+        match field.data_type() {
+            DataType::FixedSizeList(child, size) => Self::ArrayBuilderType::with_capacity(
+                <T::Element as ArrowSerialize>::new_array_for_field(child, capacity * *size as usize),
+                *size,
+                capacity,
+            )
+            .with_field(child.clone()),
+            _ => Self::new_array_with_capacity(capacity),
+        }
+    }
+
+    fn arrow_serialize(v: &T, array: &mut Self::ArrayBuilderType) -> Result<(), arrow_schema::ArrowError> {
+        let shape = S::shape();
+        let actual = v.tensor_shape();
+        if actual != shape {
+            return Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                "Tensor of shape {actual:?} does not match the declared shape {shape:?}"
+            )));
+        }
+        let values = array.values();
+        v.try_for_each_element(&mut |e| <T::Element as ArrowSerialize>::arrow_serialize(e, values))?;
+        array.append(true);
+        Ok(())
+    }
+}
+
+/// Adds the struct and field name to argument errors raised while serializing or validating a field of a derived
+/// struct.
+#[doc(hidden)]
+pub fn with_field_context(
+    err: arrow_schema::ArrowError,
+    struct_name: &str,
+    field_name: &str,
+) -> arrow_schema::ArrowError {
+    match err {
+        arrow_schema::ArrowError::InvalidArgumentError(msg) => {
+            arrow_schema::ArrowError::InvalidArgumentError(format!("{struct_name}.{field_name}: {msg}"))
+        }
+        err => err,
+    }
+}
+
+// Returns an error if growing a 32-bit offset buffer ending at `len` by `additional` bytes or values would overflow.
+// Checked before anything is appended, so builders don't panic in the middle of serialization.
+#[inline]
+pub(crate) fn check_offset_overflow(len: usize, additional: usize) -> Result<(), arrow_schema::ArrowError> {
+    match len.checked_add(additional) {
+        Some(end) if end <= i32::MAX as usize => Ok(()),
+        _ => Err(arrow_schema::ArrowError::OffsetOverflowError(
+            len.saturating_add(additional),
+        )),
+    }
+}
+
 // internal helper method to extend a mutable array
 fn arrow_serialize_extend_internal<
     'a,
     A: 'static,
     T: ArrowSerialize + ArrowField<Type = A> + 'static,
-    I: IntoIterator<Item = &'a A>,
+    I: Iterator<Item = &'a A>,
 >(
-    into_iter: I,
+    iter: I,
     array: &mut <T as ArrowSerialize>::ArrayBuilderType,
 ) -> Result<(), arrow_schema::ArrowError> {
-    let iter = into_iter.into_iter();
     for i in iter {
         <T as ArrowSerialize>::arrow_serialize(i, array)?;
     }
@@ -451,6 +1033,10 @@ fn arrow_serialize_extend_internal<
 }
 
 /// Serializes an iterator into an `arrow::ArrayBuilder`
+///
+/// The builder is created with [`ArrowSerialize::new_array_with_capacity`] using the lower bound of the
+/// iterator's `size_hint`, so iterators with an exact size (e.g. those implementing [`ExactSizeIterator`])
+/// are serialized without reallocating the top-level buffers.
 pub fn arrow_serialize_to_mutable_array<
     'a,
     A: 'static,
@@ -459,8 +1045,54 @@ pub fn arrow_serialize_to_mutable_array<
 >(
     into_iter: I,
 ) -> Result<<T as ArrowSerialize>::ArrayBuilderType, arrow_schema::ArrowError> {
-    let mut arr = <T as ArrowSerialize>::new_array();
-    arrow_serialize_extend_internal::<A, T, I>(into_iter, &mut arr)?;
+    let iter = into_iter.into_iter();
+    let mut arr = <T as ArrowSerialize>::new_array_with_capacity(iter.size_hint().0);
+    arrow_serialize_extend_internal::<A, T, _>(iter, &mut arr)?;
+    Ok(arr)
+}
+
+/// Serializes an iterator of owned values into an `arrow::ArrayBuilder`
+///
+/// Same as [`arrow_serialize_to_mutable_array`] except the iterator yields values instead of references,
+/// which avoids collecting generated values into a temporary collection first.
+pub fn arrow_serialize_owned_to_mutable_array<
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    I: IntoIterator<Item = A>,
+>(
+    into_iter: I,
+) -> Result<<T as ArrowSerialize>::ArrayBuilderType, arrow_schema::ArrowError> {
+    let iter = into_iter.into_iter();
+    let mut arr = <T as ArrowSerialize>::new_array_with_capacity(iter.size_hint().0);
+    for i in iter {
+        <T as ArrowSerialize>::arrow_serialize(&i, &mut arr)?;
+    }
+    Ok(arr)
+}
+
+/// Serializes an iterator of optional references into an `arrow::ArrayBuilder`
+///
+/// `None` items are pushed as top-level nulls, so any `T` can be serialized to a nullable array without first
+/// converting the elements to `Option<T>`.
+pub fn arrow_serialize_nullable_to_mutable_array<
+    'a,
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    I: IntoIterator<Item = Option<&'a A>>,
+>(
+    into_iter: I,
+) -> Result<<T as ArrowSerialize>::ArrayBuilderType, arrow_schema::ArrowError>
+where
+    <T as ArrowSerialize>::ArrayBuilderType: PushNull,
+{
+    let iter = into_iter.into_iter();
+    let mut arr = <T as ArrowSerialize>::new_array_with_capacity(iter.size_hint().0);
+    for i in iter {
+        match i {
+            Some(i) => <T as ArrowSerialize>::arrow_serialize(i, &mut arr)?,
+            None => arr.push_null(),
+        }
+    }
     Ok(arr)
 }
 
@@ -524,14 +1156,22 @@ where
     where
         Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
-        Ok(arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.finish())
+        <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_to_mutable_array::<
+            Element,
+            Element,
+            Collection,
+        >(self)?)
     }
 
     fn try_into_arrow_as_type<Field>(self) -> Result<ArrayRef, arrow_schema::ArrowError>
     where
         Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
-        Ok(arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.finish())
+        <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_to_mutable_array::<
+            Element,
+            Field,
+            Collection,
+        >(self)?)
     }
 }
 
@@ -546,7 +1186,11 @@ where
     {
         RecordBatch::try_from_iter([(
             "record_batch_item",
-            arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.finish(),
+            <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_to_mutable_array::<
+                Element,
+                Element,
+                Collection,
+            >(self)?)?,
         )])
     }
 
@@ -556,7 +1200,181 @@ where
     {
         RecordBatch::try_from_iter([(
             "record_batch_item",
-            arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.finish(),
+            <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_to_mutable_array::<
+                Element,
+                Field,
+                Collection,
+            >(self)?)?,
+        )])
+    }
+}
+
+/// Top-level API to serialize iterators of owned values to Arrow
+///
+/// Iterators yielding `Option<T>` are serialized as nullable arrays, since `Option<T>` implements
+/// [`ArrowSerialize`] whenever `T` does.
+pub trait TryIntoArrowOwned<ArrowArray, Element>
+where
+    Self: IntoIterator<Item = Element>,
+    Element: 'static,
+{
+    /// Convert from any iterator of owned values into an `arrow::Array`
+    fn try_into_arrow_owned(self) -> Result<ArrowArray, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static;
+
+    /// Convert from any iterator of owned values into an `arrow::Array` by coercing the conversion to a specific Arrow type.
+    fn try_into_arrow_owned_as_type<ArrowType>(self) -> Result<ArrowArray, arrow_schema::ArrowError>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static;
+}
+
+impl<Element, Collection> TryIntoArrowOwned<ArrayRef, Element> for Collection
+where
+    Element: 'static,
+    Collection: IntoIterator<Item = Element>,
+{
+    fn try_into_arrow_owned(self) -> Result<ArrayRef, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_owned_to_mutable_array::<
+            Element,
+            Element,
+            Collection,
+        >(self)?)
+    }
+
+    fn try_into_arrow_owned_as_type<Field>(self) -> Result<ArrayRef, arrow_schema::ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_owned_to_mutable_array::<
+            Element,
+            Field,
+            Collection,
+        >(self)?)
+    }
+}
+
+impl<Element, Collection> TryIntoArrowOwned<RecordBatch, Element> for Collection
+where
+    Element: 'static,
+    Collection: IntoIterator<Item = Element>,
+{
+    fn try_into_arrow_owned(self) -> Result<RecordBatch, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        RecordBatch::try_from_iter([(
+            "record_batch_item",
+            <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_owned_to_mutable_array::<
+                Element,
+                Element,
+                Collection,
+            >(self)?)?,
+        )])
+    }
+
+    fn try_into_arrow_owned_as_type<Field>(self) -> Result<RecordBatch, arrow_schema::ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        RecordBatch::try_from_iter([(
+            "record_batch_item",
+            <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_owned_to_mutable_array::<
+                Element,
+                Field,
+                Collection,
+            >(self)?)?,
+        )])
+    }
+}
+
+/// Top-level API to serialize optional references to a nullable Arrow array
+///
+/// `None` items become top-level nulls in the validity bitmap of the resulting array, which can be deserialized
+/// back into a `Vec<Option<T>>`.
+pub trait TryIntoArrowNullable<'a, ArrowArray, Element>
+where
+    Self: IntoIterator<Item = Option<&'a Element>>,
+    Element: 'static,
+{
+    /// Convert from any iterable of `Option<&Element>` into an `arrow::Array`
+    fn try_into_arrow_nullable(self) -> Result<ArrowArray, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <Element as ArrowSerialize>::ArrayBuilderType: PushNull;
+
+    /// Convert from any iterable of `Option<&Element>` into an `arrow::Array` by coercing the conversion to a specific Arrow type.
+    fn try_into_arrow_nullable_as_type<ArrowType>(self) -> Result<ArrowArray, arrow_schema::ArrowError>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <ArrowType as ArrowSerialize>::ArrayBuilderType: PushNull;
+}
+
+impl<'a, Element, Collection> TryIntoArrowNullable<'a, ArrayRef, Element> for Collection
+where
+    Element: 'static,
+    Collection: IntoIterator<Item = Option<&'a Element>>,
+{
+    fn try_into_arrow_nullable(self) -> Result<ArrayRef, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <Element as ArrowSerialize>::ArrayBuilderType: PushNull,
+    {
+        <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_nullable_to_mutable_array::<
+            Element,
+            Element,
+            Collection,
+        >(self)?)
+    }
+
+    fn try_into_arrow_nullable_as_type<Field>(self) -> Result<ArrayRef, arrow_schema::ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <Field as ArrowSerialize>::ArrayBuilderType: PushNull,
+    {
+        <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_nullable_to_mutable_array::<
+            Element,
+            Field,
+            Collection,
+        >(self)?)
+    }
+}
+
+impl<'a, Element, Collection> TryIntoArrowNullable<'a, RecordBatch, Element> for Collection
+where
+    Element: 'static,
+    Collection: IntoIterator<Item = Option<&'a Element>>,
+{
+    fn try_into_arrow_nullable(self) -> Result<RecordBatch, arrow_schema::ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <Element as ArrowSerialize>::ArrayBuilderType: PushNull,
+    {
+        RecordBatch::try_from_iter([(
+            "record_batch_item",
+            <Element as ArrowSerialize>::try_finish(&mut arrow_serialize_nullable_to_mutable_array::<
+                Element,
+                Element,
+                Collection,
+            >(self)?)?,
+        )])
+    }
+
+    fn try_into_arrow_nullable_as_type<Field>(self) -> Result<RecordBatch, arrow_schema::ArrowError>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        <Field as ArrowSerialize>::ArrayBuilderType: PushNull,
+    {
+        RecordBatch::try_from_iter([(
+            "record_batch_item",
+            <Field as ArrowSerialize>::try_finish(&mut arrow_serialize_nullable_to_mutable_array::<
+                Element,
+                Field,
+                Collection,
+            >(self)?)?,
         )])
     }
 }
@@ -0,0 +1,64 @@
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+use rayon::prelude::*;
+
+use super::{arrow_serialize_to_mutable_array, ArrowSerialize};
+use crate::field::ArrowField;
+use crate::parallel::default_chunk_size;
+
+/// Serializes a slice into an [`ArrayRef`] in parallel.
+///
+/// The slice is split into chunks of `chunk_size` elements, each chunk is serialized into its own
+/// `<T as ArrowSerialize>::new_array()` builder on the rayon thread pool, and the resulting arrays are
+/// concatenated in order.
+pub fn arrow_serialize_parallel<A, T>(values: &[A], chunk_size: usize) -> Result<ArrayRef, ArrowError>
+where
+    A: Sync + 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+{
+    let chunks = values
+        .par_chunks(chunk_size.max(1))
+        .map(|chunk| <T as ArrowSerialize>::try_finish(&mut arrow_serialize_to_mutable_array::<A, T, _>(chunk)?))
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    match chunks.as_slice() {
+        [] => <T as ArrowSerialize>::try_finish(&mut <T as ArrowSerialize>::new_array()),
+        [array] => Ok(array.clone()),
+        _ => arrow_select::concat::concat(&chunks.iter().map(|x| x.as_ref()).collect::<Vec<&dyn Array>>()),
+    }
+}
+
+/// Top-level API to serialize to Arrow in parallel, see [`arrow_serialize_parallel`].
+pub trait TryIntoArrowParallel<Element>
+where
+    Element: Sync + 'static,
+{
+    /// Convert a slice into an `arrow::Array` in parallel
+    fn try_into_arrow_par(&self) -> Result<ArrayRef, ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>;
+
+    /// Same as `try_into_arrow_par` except can coerce the conversion to a specific Arrow type.
+    fn try_into_arrow_par_as_type<ArrowType>(&self) -> Result<ArrayRef, ArrowError>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static;
+}
+
+impl<Element> TryIntoArrowParallel<Element> for [Element]
+where
+    Element: Sync + 'static,
+{
+    fn try_into_arrow_par(&self) -> Result<ArrayRef, ArrowError>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        arrow_serialize_parallel::<Element, Element>(self, default_chunk_size(self.len()))
+    }
+
+    fn try_into_arrow_par_as_type<ArrowType>(&self) -> Result<ArrayRef, ArrowError>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    {
+        arrow_serialize_parallel::<Element, ArrowType>(self, default_chunk_size(self.len()))
+    }
+}
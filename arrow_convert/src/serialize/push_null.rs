@@ -13,6 +13,8 @@ pub trait PushNull {
     fn push_null(&mut self);
 }
 
+// A null fixed size list slot still occupies `value_length` child slots. These are filled with nulls
+// that are masked by the parent validity, recursing into nested fixed size lists as needed.
 impl<T: ArrayBuilder + PushNull> PushNull for FixedSizeListBuilder<T> {
     fn push_null(&mut self) {
         let length = self.value_length();
@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use arrow_array::{builder::ArrayBuilder, ArrayRef, StructArray};
+use arrow_buffer::NullBufferBuilder;
+use arrow_schema::{DataType, Fields};
+
+use super::{ArrowSerialize, PushNull};
+use crate::field::ArrowField;
+
+// Macro to facilitate implementation of the tuple builders. Each arity gets its own builder struct
+// holding one child builder per element, mirroring the builders generated for derived structs.
+macro_rules! impl_tuple_builder {
+    ($builder:ident; $($name:ident: $field:ident: $idx:tt),+) => {
+        /// [`ArrayBuilder`] for tuples, producing a [`StructArray`] with one child per tuple element.
+        pub struct $builder<$($name),+>
+        where
+            $($name: ArrowSerialize,)+
+        {
+            $($field: <$name as ArrowSerialize>::ArrayBuilderType,)+
+            fields: Fields,
+            nulls: NullBufferBuilder,
+        }
+
+        impl<$($name),+> $builder<$($name),+>
+        where
+            $($name: ArrowSerialize,)+
+        {
+            /// Creates a new builder for the tuple.
+            pub fn new() -> Self {
+                let DataType::Struct(fields) = <($($name,)+) as ArrowField>::data_type() else {
+                    unreachable!("tuples are always mapped to structs")
+                };
+                Self {
+                    $($field: <$name as ArrowSerialize>::new_array(),)+
+                    fields,
+                    nulls: NullBufferBuilder::new(0),
+                }
+            }
+
+            /// Creates a new builder for the tuple with room for at least `capacity` tuples.
+            pub fn with_capacity(capacity: usize) -> Self {
+                let DataType::Struct(fields) = <($($name,)+) as ArrowField>::data_type() else {
+                    unreachable!("tuples are always mapped to structs")
+                };
+                Self {
+                    $($field: <$name as ArrowSerialize>::new_array_with_capacity(capacity),)+
+                    fields,
+                    nulls: NullBufferBuilder::new(capacity),
+                }
+            }
+
+            /// Creates a new builder for the tuple with room for at least `capacity` tuples, building the child
+            /// builders from the declared struct `fields`. Fails if there isn't one field per tuple element.
+            pub fn try_with_fields(fields: Fields, capacity: usize) -> Result<Self, arrow_schema::ArrowError> {
+                let arity = [$($idx),+].len();
+                if fields.len() != arity {
+                    return Err(arrow_schema::ArrowError::InvalidArgumentError(format!(
+                        "{} expects {} struct fields, got {}",
+                        stringify!($builder),
+                        arity,
+                        fields.len()
+                    )));
+                }
+                Ok(Self {
+                    $($field: <$name as ArrowSerialize>::new_array_for_field(&fields[$idx], capacity),)+
+                    fields,
+                    nulls: NullBufferBuilder::new(capacity),
+                })
+            }
+
+            /// Serializes a tuple into the child builders and marks the slot as valid.
+            pub fn try_push(
+                &mut self,
+                v: &($(<$name as ArrowField>::Type,)+),
+            ) -> Result<(), arrow_schema::ArrowError> {
+                $(<$name as ArrowSerialize>::arrow_serialize(&v.$idx, &mut self.$field)?;)+
+                self.nulls.append_non_null();
+                Ok(())
+            }
+
+            /// Finishes the builder, returning an error instead of panicking if the children don't match the
+            /// declared struct fields.
+            pub fn try_finish(&mut self) -> Result<ArrayRef, arrow_schema::ArrowError> {
+                let values = vec![$(<$name as ArrowSerialize>::try_finish(&mut self.$field)?,)+];
+                Ok(Arc::new(StructArray::try_new(self.fields.clone(), values, self.nulls.finish())?))
+            }
+
+            /// Same as [`Self::try_finish`], without resetting the builder.
+            pub fn try_finish_cloned(&self) -> Result<ArrayRef, arrow_schema::ArrowError> {
+                let values = vec![$(self.$field.finish_cloned(),)+];
+                Ok(Arc::new(StructArray::try_new(
+                    self.fields.clone(),
+                    values,
+                    self.nulls.finish_cloned(),
+                )?))
+            }
+        }
+
+        impl<$($name),+> Default for $builder<$($name),+>
+        where
+            $($name: ArrowSerialize,)+
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<$($name),+> std::fmt::Debug for $builder<$($name),+>
+        where
+            $($name: ArrowSerialize,)+
+            $(<$name as ArrowSerialize>::ArrayBuilderType: std::fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($builder))
+                    $(.field(stringify!($field), &self.$field))+
+                    .field("fields", &self.fields)
+                    .finish()
+            }
+        }
+
+        impl<$($name),+> PushNull for $builder<$($name),+>
+        where
+            $($name: ArrowSerialize,)+
+            $(<$name as ArrowSerialize>::ArrayBuilderType: PushNull,)+
+        {
+            fn push_null(&mut self) {
+                $(self.$field.push_null();)+
+                self.nulls.append_null();
+            }
+        }
+
+        impl<$($name),+> ArrayBuilder for $builder<$($name),+>
+        where
+            $($name: ArrowSerialize + 'static,)+
+        {
+            fn len(&self) -> usize {
+                self.nulls.len()
+            }
+
+            fn finish(&mut self) -> ArrayRef {
+                self.try_finish()
+                    .unwrap_or_else(|e| panic!("failed to finish {}: {}", stringify!($builder), e))
+            }
+
+            fn finish_cloned(&self) -> ArrayRef {
+                self.try_finish_cloned()
+                    .unwrap_or_else(|e| panic!("failed to finish {}: {}", stringify!($builder), e))
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+                self
+            }
+
+            fn into_box_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+                self
+            }
+        }
+
+        impl<$($name),+> ArrowSerialize for ($($name,)+)
+        where
+            $($name: ArrowSerialize + 'static,)+
+        {
+            type ArrayBuilderType = $builder<$($name),+>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::with_capacity(capacity)
+            }
+
+            fn new_array_for_field(field: &arrow_schema::Field, capacity: usize) -> Self::ArrayBuilderType {
+                match field.data_type() {
+                    DataType::Struct(fields) => Self::ArrayBuilderType::try_with_fields(fields.clone(), capacity)
+                        .unwrap_or_else(|_| Self::new_array_with_capacity(capacity)),
+                    _ => Self::new_array_with_capacity(capacity),
+                }
+            }
+
+            #[inline]
+            fn try_finish(array: &mut Self::ArrayBuilderType) -> Result<ArrayRef, arrow_schema::ArrowError> {
+                array.try_finish()
+            }
+
+            #[inline]
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::ArrayBuilderType,
+            ) -> Result<(), arrow_schema::ArrowError> {
+                array.try_push(v)
+            }
+        }
+    };
+}
+
+impl_tuple_builder!(Tuple1Builder; A: field_0: 0);
+impl_tuple_builder!(Tuple2Builder; A: field_0: 0, B: field_1: 1);
+impl_tuple_builder!(Tuple3Builder; A: field_0: 0, B: field_1: 1, C: field_2: 2);
+impl_tuple_builder!(Tuple4Builder; A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3);
+impl_tuple_builder!(Tuple5Builder; A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4);
+impl_tuple_builder!(
+    Tuple6Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5
+);
+impl_tuple_builder!(
+    Tuple7Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6
+);
+impl_tuple_builder!(
+    Tuple8Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7
+);
+impl_tuple_builder!(
+    Tuple9Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8
+);
+impl_tuple_builder!(
+    Tuple10Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9
+);
+impl_tuple_builder!(
+    Tuple11Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9, K: field_10: 10
+);
+impl_tuple_builder!(
+    Tuple12Builder;
+    A: field_0: 0, B: field_1: 1, C: field_2: 2, D: field_3: 3, E: field_4: 4, F: field_5: 5, G: field_6: 6,
+    H: field_7: 7, I: field_8: 8, J: field_9: 9, K: field_10: 10, L: field_11: 11
+);
@@ -0,0 +1,142 @@
+use arrow::array::{Array, ArrayRef};
+use arrow_convert::deserialize::arrow_array_deserialize_iterator_borrowed;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_borrowed_str() {
+    let original = vec![Some("hello".to_string()), None, Some("world".to_string())];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let borrowed: Vec<Option<&str>> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(borrowed, vec![Some("hello"), None, Some("world")]);
+
+    // the values point into the array's buffer
+    let values = b.as_any().downcast_ref::<arrow::array::StringArray>().unwrap().values();
+    assert!(values.as_ptr_range().contains(&borrowed[0].unwrap().as_ptr()));
+}
+
+#[test]
+fn test_borrowed_binary() {
+    let original = vec![b"ab".to_vec(), vec![], b"cde".to_vec()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let borrowed: Vec<&[u8]> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(borrowed, vec![&b"ab"[..], &[], &b"cde"[..]]);
+}
+
+#[test]
+fn test_borrowed_primitive_slice() {
+    let original = vec![Some(vec![1_i64, 2, 3]), None, Some(vec![]), Some(vec![4])];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let borrowed: Vec<Option<&[i64]>> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(
+        borrowed,
+        vec![Some(&[1, 2, 3][..]), None, Some(&[][..]), Some(&[4][..])]
+    );
+
+    // offsets are honoured for sliced arrays
+    let sliced = b.slice(3, 1);
+    let borrowed: Vec<Option<&[i64]>> = arrow_array_deserialize_iterator_borrowed(sliced.as_ref())
+        .unwrap()
+        .collect();
+    assert_eq!(borrowed, vec![Some(&[4][..])]);
+}
+
+#[test]
+fn test_borrowed_type_mismatch() {
+    let b: ArrayRef = vec![1_i32, 2].try_into_arrow().unwrap();
+    assert!(arrow_array_deserialize_iterator_borrowed::<&str>(b.as_ref()).is_err());
+}
+
+#[test]
+fn test_borrowed_struct() {
+    // `Row` deriving `ArrowDeserialize` must not claim the `RowRef` name unless it opts into a row view
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        name: String,
+        tag: Option<String>,
+        id: i64,
+        samples: Vec<f32>,
+        inner: Inner,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Inner {
+        payload: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct RowRef<'a> {
+        name: &'a str,
+        tag: Option<&'a str>,
+        id: i64,
+        samples: &'a [f32],
+        inner: InnerRef<'a>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct InnerRef<'a> {
+        payload: &'a [u8],
+    }
+
+    let original = vec![
+        Row {
+            name: "a".to_string(),
+            tag: Some("x".to_string()),
+            id: 1,
+            samples: vec![1.0, 2.0],
+            inner: Inner { payload: vec![1, 2] },
+        },
+        Row {
+            name: "b".to_string(),
+            tag: None,
+            id: 2,
+            samples: vec![],
+            inner: Inner { payload: vec![] },
+        },
+    ];
+    assert_eq!(
+        <RowRef as arrow_convert::field::ArrowField>::data_type(),
+        <Row as arrow_convert::field::ArrowField>::data_type()
+    );
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let borrowed: Vec<RowRef> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(
+        borrowed,
+        vec![
+            RowRef {
+                name: "a",
+                tag: Some("x"),
+                id: 1,
+                samples: &[1.0, 2.0],
+                inner: InnerRef { payload: &[1, 2] },
+            },
+            RowRef {
+                name: "b",
+                tag: None,
+                id: 2,
+                samples: &[],
+                inner: InnerRef { payload: &[] },
+            },
+        ]
+    );
+
+    let optional: Vec<Option<RowRef>> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(optional.len(), 2);
+    assert!(optional.iter().all(Option::is_some));
+}
+
+#[test]
+fn test_borrowed_transparent_struct() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Name<'n>(&'n str);
+
+    let b: ArrayRef = vec!["a".to_string(), "b".to_string()].try_into_arrow().unwrap();
+    let borrowed: Vec<Name> = arrow_array_deserialize_iterator_borrowed(b.as_ref()).unwrap().collect();
+    assert_eq!(borrowed, vec![Name("a"), Name("b")]);
+}
@@ -0,0 +1,104 @@
+//! Tests for zero-copy binary deserialization into `Buffer` and `bytes::Bytes`.
+use arrow::array::{Array, ArrayRef, BinaryArray};
+use arrow::buffer::{Buffer, ScalarBuffer};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::serialize::TryIntoArrow;
+use pretty_assertions::assert_eq;
+
+// Returns true if `value` points into the value buffer of the binary array `array`.
+fn shares_values(array: &ArrayRef, value: &[u8]) -> bool {
+    let values = array.as_any().downcast_ref::<BinaryArray>().unwrap().values();
+    values.as_ptr_range().contains(&value.as_ptr())
+}
+
+#[test]
+fn test_buffer_zero_copy() {
+    let original = vec![
+        Some(Buffer::from(b"hello".as_slice())),
+        None,
+        Some(Buffer::from(b"world".as_slice())),
+    ];
+    let array: ArrayRef = original.try_into_arrow().unwrap();
+
+    let round_trip: Vec<Option<Buffer>> = array.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+    assert!(shares_values(&array, round_trip[2].as_ref().unwrap()));
+
+    let round_trip: Vec<Option<ScalarBuffer<u8>>> = array.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip[0].as_deref(), Some(b"hello".as_slice()));
+    assert!(shares_values(&array, round_trip[0].as_ref().unwrap()));
+}
+
+#[test]
+fn test_buffer_binary_array_borrowed_slices() {
+    use arrow::datatypes::DataType;
+    use arrow_convert::deserialize::{ArrowDeserialize, BufferBinaryArray};
+
+    // Custom types can still deserialize from borrowed byte slices through `BufferBinaryArray`.
+    #[derive(Debug, PartialEq)]
+    struct Checksum(u32);
+
+    impl arrow_convert::field::ArrowField for Checksum {
+        type Type = Self;
+
+        fn data_type() -> DataType {
+            DataType::Binary
+        }
+    }
+
+    impl ArrowDeserialize for Checksum {
+        type ArrayType = BufferBinaryArray;
+
+        fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+            v.map(|bytes| Checksum(bytes.iter().map(|b| *b as u32).sum()))
+        }
+    }
+
+    arrow_convert::arrow_enable_vec_for_type!(Checksum);
+
+    let array: ArrayRef = vec![Buffer::from(b"\x01\x02".as_slice())].try_into_arrow().unwrap();
+    let checksums: Vec<Checksum> = array.try_into_collection().unwrap();
+    assert_eq!(checksums, vec![Checksum(3)]);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_bytes_roundtrip() {
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use bytes::Bytes;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Message {
+        key: Option<Bytes>,
+        payload: Bytes,
+        chunks: Vec<Bytes>,
+    }
+
+    let original = vec![
+        Message {
+            key: Some(Bytes::from_static(b"k1")),
+            payload: Bytes::from(vec![0, 1, 2, 255]),
+            chunks: vec![Bytes::from_static(b"a"), Bytes::new()],
+        },
+        Message {
+            key: None,
+            payload: Bytes::new(),
+            chunks: vec![],
+        },
+    ];
+
+    let schema = Message::arrow_schema();
+    assert_eq!(schema.field(1).data_type(), &arrow::datatypes::DataType::Binary);
+
+    let array: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Message> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let array: ArrayRef = vec![Bytes::from_static(b"shared")].try_into_arrow().unwrap();
+    let round_trip: Vec<Bytes> = array.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip[0], "shared");
+    assert!(shares_values(&array, &round_trip[0]));
+    // the bytes stay valid after the array is dropped
+    drop(array);
+    assert_eq!(round_trip[0], "shared");
+}
@@ -0,0 +1,122 @@
+use arrow::array::{Array, ArrayBuilder, ArrayRef, RecordBatch};
+use arrow::error::ArrowError;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::serialize::*;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+/// A value whose builder reports an offset overflow once it holds `LIMIT` values, standing in for a column
+/// that would exceed `i32::MAX` bytes without allocating gigabytes in tests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Limited(u32);
+
+const LIMIT: usize = 3;
+
+impl arrow_convert::field::ArrowField for Limited {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow::datatypes::DataType {
+        arrow::datatypes::DataType::UInt32
+    }
+}
+
+impl arrow_convert::serialize::ArrowSerialize for Limited {
+    type ArrayBuilderType = arrow::array::UInt32Builder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        if array.len() >= LIMIT {
+            return Err(ArrowError::OffsetOverflowError(array.len() + 1));
+        }
+        array.append_value(v.0);
+        Ok(())
+    }
+}
+
+impl arrow_convert::deserialize::ArrowDeserialize for Limited {
+    type ArrayType = arrow::array::UInt32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<u32>) -> Option<Self> {
+        v.map(Limited)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Row {
+    name: String,
+    // serialized after `name`, so an overflow leaves a partially written row in the builder
+    limited: Limited,
+}
+
+#[test]
+fn test_chunked_splits_on_overflow() {
+    let original = (0..8).map(Limited).collect::<Vec<_>>();
+
+    // the regular entry point surfaces the error
+    let result: Result<ArrayRef, _> = original.try_into_arrow();
+    assert!(matches!(result, Err(ArrowError::OffsetOverflowError(_))));
+
+    let arrays: Vec<ArrayRef> = original.try_into_arrow_chunked().unwrap();
+    assert_eq!(
+        arrays.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![3, 3, 2]
+    );
+
+    let round_trip = arrays
+        .iter()
+        .flat_map(|x| -> Vec<Limited> { x.clone().try_into_collection().unwrap() })
+        .collect::<Vec<_>>();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_chunked_partially_written_rows() {
+    let original = (0..7)
+        .map(|x| Row {
+            name: format!("row {x}"),
+            limited: Limited(x),
+        })
+        .collect::<Vec<_>>();
+
+    let batches: Vec<RecordBatch> = original.try_into_arrow_chunked().unwrap();
+    assert_eq!(
+        batches.iter().map(|x| x.num_rows()).collect::<Vec<_>>(),
+        vec![3, 3, 1]
+    );
+
+    let round_trip = batches
+        .iter()
+        .flat_map(|x| -> Vec<Row> { x.column(0).clone().try_into_collection().unwrap() })
+        .collect::<Vec<_>>();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_chunked_without_overflow() {
+    let original = vec!["a".to_string(), "b".to_string()];
+    let arrays: Vec<ArrayRef> = original.try_into_arrow_chunked().unwrap();
+    assert_eq!(arrays.len(), 1);
+    let expected: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(arrays[0].to_data(), expected.to_data());
+
+    // an empty slice still produces a single empty array with the right type
+    let arrays: Vec<ArrayRef> = Vec::<Option<String>>::new().try_into_arrow_chunked().unwrap();
+    assert_eq!(arrays.len(), 1);
+    assert_eq!(arrays[0].len(), 0);
+    assert_eq!(arrays[0].data_type(), &arrow::datatypes::DataType::Utf8);
+}
+
+#[test]
+#[ignore = "allocates more than 2 GiB"]
+fn test_string_offset_overflow_is_an_error() {
+    let original = vec![String::new(), "a".repeat(i32::MAX as usize + 1)];
+    let result: Result<ArrayRef, _> = original.try_into_arrow();
+    assert!(matches!(result, Err(ArrowError::OffsetOverflowError(_))));
+}
@@ -0,0 +1,100 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use arrow::array::{Array, ArrayRef};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, LargeString};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_collections_schema() {
+    let expected = <Vec<i32> as ArrowField>::data_type();
+    assert_eq!(<VecDeque<i32> as ArrowField>::data_type(), expected);
+    assert_eq!(<BTreeSet<i32> as ArrowField>::data_type(), expected);
+    assert_eq!(<HashSet<i32> as ArrowField>::data_type(), expected);
+}
+
+#[test]
+fn test_vec_deque_round_trip() {
+    let original = vec![VecDeque::from([3_i64, 1, 2]), VecDeque::new()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<VecDeque<i64>> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // VecDeque and Vec share the same representation
+    let as_vec: Vec<Vec<i64>> = b.try_into_collection().unwrap();
+    assert_eq!(as_vec, vec![vec![3, 1, 2], vec![]]);
+}
+
+#[test]
+fn test_btree_set_round_trip() {
+    let original = vec![
+        BTreeSet::from(["b".to_string(), "a".to_string(), "c".to_string()]),
+        BTreeSet::new(),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    // sets are serialized in sorted order
+    let as_vec: Vec<Vec<String>> = b.clone().try_into_collection().unwrap();
+    assert_eq!(as_vec[0], vec!["a", "b", "c"]);
+
+    let round_trip: Vec<BTreeSet<String>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_hash_set_round_trip() {
+    let original = vec![Some(HashSet::from([1_u32, 2, 3])), None, Some(HashSet::new())];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<HashSet<u32>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_collections_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Model {
+        tags: BTreeSet<String>,
+        ids: HashSet<i64>,
+        #[arrow_field(type = "VecDeque<LargeString>")]
+        history: VecDeque<String>,
+        nested: Vec<BTreeSet<i16>>,
+    }
+
+    let original = vec![
+        Model {
+            tags: BTreeSet::from(["x".to_string(), "y".to_string()]),
+            ids: HashSet::from([10, 20]),
+            history: VecDeque::from(["first".to_string(), "second".to_string()]),
+            nested: vec![BTreeSet::from([1, 2]), BTreeSet::new()],
+        },
+        Model {
+            tags: BTreeSet::new(),
+            ids: HashSet::new(),
+            history: VecDeque::new(),
+            nested: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Model> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec_round_trip() {
+    use smallvec::{smallvec, SmallVec};
+
+    let original: Vec<SmallVec<[f64; 4]>> = vec![smallvec![1.0, 2.0], smallvec![1.0, 2.0, 3.0, 4.0, 5.0], smallvec![]];
+    assert_eq!(
+        <SmallVec<[f64; 4]> as ArrowField>::data_type(),
+        <Vec<f64> as ArrowField>::data_type()
+    );
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<SmallVec<[f64; 4]>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
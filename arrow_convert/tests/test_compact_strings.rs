@@ -0,0 +1,63 @@
+//! Tests for the `smol_str`, `compact_str` and `arcstr` string types.
+#[cfg(all(feature = "smol_str", feature = "compact_str", feature = "arcstr"))]
+mod compact_string_tests {
+    use arcstr::ArcStr;
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use compact_str::CompactString;
+    use pretty_assertions::assert_eq;
+    use smol_str::SmolStr;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Symbol {
+        ticker: SmolStr,
+        venue: Option<CompactString>,
+        aliases: Vec<ArcStr>,
+    }
+
+    #[test]
+    fn test_compact_strings_roundtrip() {
+        let schema = Symbol::arrow_schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::List(Field::new("_item", DataType::Utf8, false).into())
+        );
+
+        let original = vec![
+            Symbol {
+                ticker: SmolStr::new("AAPL"),
+                venue: Some(CompactString::new(
+                    "a venue name longer than the inline capacity",
+                )),
+                aliases: vec![ArcStr::from("Apple"), arcstr::literal!("Apple Inc.")],
+            },
+            Symbol {
+                ticker: SmolStr::new(""),
+                venue: None,
+                aliases: vec![],
+            },
+        ];
+
+        let array: ArrayRef = original.try_into_arrow().unwrap();
+        let round_trip: Vec<Symbol> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_compact_strings_interchangeable_with_string() {
+        let array: ArrayRef = vec![Some(SmolStr::new("a")), None].try_into_arrow().unwrap();
+        let strings = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(strings.value(0), "a");
+        assert!(strings.is_null(1));
+
+        let as_string: Vec<Option<String>> = array.clone().try_into_collection().unwrap();
+        assert_eq!(as_string, vec![Some("a".to_string()), None]);
+        let as_compact: Vec<Option<CompactString>> = array.try_into_collection().unwrap();
+        assert_eq!(as_compact, vec![Some(CompactString::new("a")), None]);
+    }
+}
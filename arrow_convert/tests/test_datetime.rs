@@ -314,3 +314,106 @@ fn test_datetime_utc_nanosecond_precision() {
     assert_eq!(original_array[0].timestamp_subsec_nanos(), 123_456_789);
     assert_eq!(round_trip[0].timestamp_subsec_nanos(), 123_456_789);
 }
+
+/// Timestamps outside the nanosecond range are an error instead of a silent null
+#[test]
+fn test_out_of_range_timestamp_is_an_error() {
+    use chrono::NaiveDate;
+
+    let too_early = NaiveDate::from_ymd_opt(1500, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    let result: Result<ArrayRef> = [too_early].try_into_arrow();
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("1500-01-01 00:00:00 is out of range"), "{err}");
+
+    let result: Result<ArrayRef> = [Some(too_early.and_utc()), None].try_into_arrow();
+    assert!(result.is_err());
+
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct Event {
+        at: DateTime<Utc>,
+    }
+
+    let result: Result<ArrayRef> = [Event {
+        at: too_early.and_utc(),
+    }]
+    .try_into_arrow();
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("Event.at: 1500-01-01 00:00:00 UTC is out of range"),
+        "{err}"
+    );
+}
+
+/// Out-of-range policies for temporal fields
+#[test]
+fn test_out_of_range_timestamp_policies() {
+    use arrow::array::{Array, TimestampNanosecondArray};
+    use arrow_convert::field::{ClampTemporal, NullTemporal};
+    use chrono::NaiveDate;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "ClampTemporal<NaiveDateTime>")]
+        clamped: NaiveDateTime,
+        #[arrow_field(type = "NullTemporal<DateTime<Utc>>")]
+        nulled: Option<DateTime<Utc>>,
+        #[arrow_field(type = "Vec<ClampTemporal<NaiveDate>>")]
+        dates: Vec<NaiveDate>,
+    }
+
+    let too_early = NaiveDate::from_ymd_opt(1500, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let too_late = NaiveDate::from_ymd_opt(3000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let in_range = NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    let original = vec![
+        Event {
+            clamped: too_early,
+            nulled: Some(too_late.and_utc()),
+            dates: vec![NaiveDate::MIN, NaiveDate::MAX],
+        },
+        Event {
+            clamped: too_late,
+            nulled: Some(in_range.and_utc()),
+            dates: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let fields = Event::arrow_schema().fields;
+    assert!(fields[1].is_nullable());
+    assert_eq!(
+        fields[1].data_type(),
+        &<DateTime<Utc> as ArrowField>::data_type()
+    );
+
+    let struct_array = b.as_any().downcast_ref::<arrow::array::StructArray>().unwrap();
+    let clamped = struct_array
+        .column(0)
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert_eq!(clamped.values().to_vec(), vec![i64::MIN, i64::MAX]);
+    assert_eq!(struct_array.column(1).null_count(), 1);
+
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip[0].nulled, None);
+    assert_eq!(round_trip[1].nulled, Some(in_range.and_utc()));
+    assert_eq!(round_trip[0].dates, vec![NaiveDate::MIN, NaiveDate::MAX]);
+    assert_eq!(
+        round_trip[1].clamped,
+        DateTime::<Utc>::from_timestamp_nanos(i64::MAX).naive_utc()
+    );
+}
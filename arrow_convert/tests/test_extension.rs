@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Float64Array, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow_convert::deserialize::{
+    check_extension_type, record_batch_project, FieldArray, TryIntoCollection, TypedArray,
+};
+use arrow_convert::field::ArrowExtension;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Celsius(f64);
+
+impl arrow_convert::field::ArrowField for Celsius {
+    type Type = Self;
+
+    fn data_type() -> DataType {
+        DataType::Float64
+    }
+
+    fn field(name: &str) -> Field {
+        <Self as ArrowExtension>::extension_field(name)
+    }
+}
+
+impl ArrowExtension for Celsius {
+    const NAME: &'static str = "example.celsius";
+
+    fn metadata() -> Option<String> {
+        Some(r#"{"unit":"C"}"#.to_string())
+    }
+}
+
+impl arrow_convert::serialize::ArrowSerialize for Celsius {
+    type ArrayBuilderType = arrow::array::Float64Builder;
+
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v.0);
+        Ok(())
+    }
+}
+
+impl arrow_convert::deserialize::ArrowDeserialize for Celsius {
+    type ArrayType = Float64Array;
+
+    fn arrow_deserialize(v: Option<f64>) -> Option<Self> {
+        v.map(Celsius)
+    }
+}
+
+arrow_convert::arrow_enable_vec_for_type!(Celsius);
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Reading {
+    temperature: Celsius,
+    previous: Option<Celsius>,
+    history: Vec<Celsius>,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+struct Wrapped(Celsius);
+
+fn readings() -> Vec<Reading> {
+    vec![Reading {
+        temperature: Celsius(21.5),
+        previous: None,
+        history: vec![Celsius(20.0), Celsius(19.5)],
+    }]
+}
+
+fn assert_extension(field: &Field) {
+    assert_eq!(field.extension_type_name(), Some("example.celsius"));
+    assert_eq!(field.extension_type_metadata(), Some(r#"{"unit":"C"}"#));
+}
+
+#[test]
+fn test_extension_field_metadata() {
+    let schema = Reading::arrow_schema();
+    assert_extension(schema.field(0));
+    assert_extension(schema.field(1));
+    assert!(schema.field(1).is_nullable());
+    match schema.field(2).data_type() {
+        DataType::List(item) => assert_extension(item),
+        data_type => panic!("unexpected data type {data_type:?}"),
+    }
+    assert_extension(&<Wrapped as arrow_convert::field::ArrowField>::field("wrapped"));
+
+    let array: ArrayRef = readings().try_into_arrow().unwrap();
+    let round_trip: Vec<Reading> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, readings());
+}
+
+#[test]
+fn test_extension_union_variant() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Measurement {
+        Temperature(Celsius),
+        Count(i64),
+    }
+
+    match <Measurement as arrow_convert::field::ArrowField>::data_type() {
+        DataType::Union(fields, _) => assert_extension(fields.iter().next().unwrap().1),
+        data_type => panic!("unexpected data type {data_type:?}"),
+    }
+}
+
+#[test]
+fn test_check_extension_type() {
+    let untagged = Field::new("t", DataType::Float64, false);
+    let tagged = <Celsius as arrow_convert::field::ArrowField>::field("t");
+    let other = Field::new("t", DataType::Float64, false).with_metadata(
+        [(
+            "ARROW:extension:name".to_string(),
+            "example.fahrenheit".to_string(),
+        )]
+        .into(),
+    );
+
+    assert!(check_extension_type::<Celsius>(&untagged).is_ok());
+    assert!(check_extension_type::<Celsius>(&tagged).is_ok());
+    let err = check_extension_type::<Celsius>(&other).unwrap_err().to_string();
+    assert!(err.contains("example.fahrenheit"), "{err}");
+    assert!(check_extension_type::<f64>(&tagged).is_err());
+}
+
+#[test]
+fn test_project_verifies_extension() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Row {
+        temperature: Celsius,
+    }
+
+    let column: ArrayRef = Arc::new(Float64Array::from(vec![21.5]));
+    let batch = |field: Field| RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![column.clone()]).unwrap();
+
+    // columns written without extension metadata are accepted
+    let projected = record_batch_project::<Row>(&batch(Field::new("temperature", DataType::Float64, false))).unwrap();
+    let rows: Vec<Row> = projected.try_into_collection().unwrap();
+    assert_eq!(
+        rows,
+        vec![Row {
+            temperature: Celsius(21.5)
+        }]
+    );
+
+    let tagged = <Celsius as arrow_convert::field::ArrowField>::field("temperature");
+    assert!(record_batch_project::<Row>(&batch(tagged)).is_ok());
+
+    let other = Field::new("temperature", DataType::Float64, false).with_metadata(
+        [(
+            "ARROW:extension:name".to_string(),
+            "example.fahrenheit".to_string(),
+        )]
+        .into(),
+    );
+    let err = record_batch_project::<Row>(&batch(other)).unwrap_err().to_string();
+    assert!(
+        err.contains("Extension type mismatch for field 'temperature'"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_field_array_verifies_extension() {
+    let column: ArrayRef = Arc::new(Float64Array::from(vec![21.5, 19.0]));
+    let tagged = <Celsius as arrow_convert::field::ArrowField>::field("temperature");
+    let untagged = Field::new("temperature", DataType::Float64, false);
+    let other = Field::new("temperature", DataType::Float64, false).with_metadata(
+        [(
+            "ARROW:extension:name".to_string(),
+            "example.fahrenheit".to_string(),
+        )]
+        .into(),
+    );
+
+    let values: Vec<Celsius> = FieldArray::new(&tagged, column.clone()).try_into_collection().unwrap();
+    assert_eq!(values, vec![Celsius(21.5), Celsius(19.0)]);
+    let values: Vec<Celsius> = FieldArray::new(&untagged, column.clone())
+        .try_into_collection()
+        .unwrap();
+    assert_eq!(values, vec![Celsius(21.5), Celsius(19.0)]);
+
+    // the storage type matches, so only the field reveals the mismatch
+    let result: Result<Vec<Celsius>, _> = FieldArray::new(&other, column.clone()).try_into_collection();
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("Extension type mismatch for field 'temperature'"),
+        "{err}"
+    );
+    let result: Result<Vec<f64>, _> = FieldArray::new(&tagged, column.clone()).try_into_collection();
+    assert!(result.is_err());
+    assert!(TypedArray::<Celsius>::try_new_with_field(&other, column.clone()).is_err());
+    assert!(TypedArray::<Celsius>::try_new_with_field(&tagged, column.clone()).is_ok());
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(vec![other])), vec![column]).unwrap();
+    let result: Result<Vec<Celsius>, _> = FieldArray::from_record_batch(&batch, "temperature")
+        .unwrap()
+        .try_into_collection();
+    assert!(result.is_err());
+    assert!(FieldArray::from_record_batch(&batch, "missing").is_err());
+}
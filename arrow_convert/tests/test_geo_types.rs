@@ -0,0 +1,144 @@
+#[cfg(feature = "geo-types")]
+mod geo_types_tests {
+    use arrow::array::{Array, ArrayRef};
+    use arrow::datatypes::{DataType, Field, Fields};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{GeoArrow, SeparatedCoord, UnknownCrs, Wgs84};
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use geo_types::{
+        coord, line_string, point, polygon, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Feature {
+        id: u32,
+        #[arrow_field(type = "GeoArrow<Point<f64>, Wgs84>")]
+        location: Point<f64>,
+        outline: Option<Polygon<f64>>,
+        route: LineString<f64>,
+    }
+
+    fn square() -> Polygon<f64> {
+        polygon![
+            exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+        ]
+    }
+
+    fn features() -> Vec<Feature> {
+        vec![
+            Feature {
+                id: 1,
+                location: point!(x: 10.0, y: 20.0),
+                outline: Some(square()),
+                route: line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)],
+            },
+            Feature {
+                id: 2,
+                location: point!(x: -1.5, y: 2.5),
+                outline: None,
+                route: LineString::new(vec![]),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_geometry_round_trip() {
+        let schema = Feature::arrow_schema();
+        assert_eq!(schema.field(1).extension_type_name(), Some("geoarrow.point"));
+        assert_eq!(
+            schema.field(1).extension_type_metadata(),
+            Some(r#"{"crs":"OGC:CRS84","crs_type":"authority_code"}"#)
+        );
+        assert_eq!(schema.field(2).extension_type_name(), Some("geoarrow.polygon"));
+        assert_eq!(schema.field(2).extension_type_metadata(), Some("{}"));
+        assert!(schema.field(2).is_nullable());
+        assert_eq!(
+            schema.field(3).extension_type_name(),
+            Some("geoarrow.linestring")
+        );
+
+        let coords = DataType::FixedSizeList(Field::new("xy", DataType::Float64, false).into(), 2);
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::List(
+                Field::new(
+                    "rings",
+                    DataType::List(Field::new("vertices", coords, false).into()),
+                    false
+                )
+                .into()
+            )
+        );
+
+        let array: ArrayRef = features().try_into_arrow().unwrap();
+        let round_trip: Vec<Feature> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, features());
+    }
+
+    #[test]
+    fn test_multi_geometries() {
+        let multi_points = vec![MultiPoint::new(vec![point!(x: 1.0, y: 2.0), point!(x: 3.0, y: 4.0)])];
+        let array: ArrayRef = multi_points.try_into_arrow().unwrap();
+        assert_eq!(
+            <MultiPoint<f64> as arrow_convert::field::ArrowField>::field("g").extension_type_name(),
+            Some("geoarrow.multipoint")
+        );
+        let round_trip: Vec<MultiPoint<f64>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, multi_points);
+
+        let multi_line_strings = vec![
+            MultiLineString::new(vec![
+                line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0)],
+                line_string![(x: 2.0, y: 2.0)],
+            ]),
+            MultiLineString::new(vec![]),
+        ];
+        let array: ArrayRef = multi_line_strings.try_into_arrow().unwrap();
+        let round_trip: Vec<MultiLineString<f64>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, multi_line_strings);
+
+        let multi_polygons = vec![MultiPolygon::new(vec![square(), square()])];
+        let array: ArrayRef = multi_polygons.try_into_arrow().unwrap();
+        assert_eq!(
+            <MultiPolygon<f64> as arrow_convert::field::ArrowField>::field("g").extension_type_name(),
+            Some("geoarrow.multipolygon")
+        );
+        let round_trip: Vec<MultiPolygon<f64>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, multi_polygons);
+    }
+
+    #[test]
+    fn test_separated_coordinates() {
+        type Separated = GeoArrow<LineString<f64>, UnknownCrs, SeparatedCoord>;
+
+        let values = vec![
+            line_string![(x: 0.0, y: 1.0), (x: 2.0, y: 3.0)],
+            LineString::from(vec![coord! { x: 5.0, y: 6.0 }]),
+        ];
+        let array: ArrayRef = values.try_into_arrow_as_type::<Separated>().unwrap();
+        assert_eq!(
+            array.data_type(),
+            &DataType::List(
+                Field::new(
+                    "vertices",
+                    DataType::Struct(Fields::from(vec![
+                        Field::new("x", DataType::Float64, false),
+                        Field::new("y", DataType::Float64, false),
+                    ])),
+                    false
+                )
+                .into()
+            )
+        );
+
+        let round_trip: Vec<LineString<f64>> = array.clone().try_into_collection_as_type::<Separated>().unwrap();
+        assert_eq!(round_trip, values);
+
+        // interleaved and separated coordinates are not interchangeable
+        let result: Result<Vec<LineString<f64>>, _> = array.try_into_collection();
+        assert!(result.is_err());
+    }
+}
@@ -187,3 +187,198 @@ fn test_glam_edge_values() {
         .expect("Failed to convert from Arrow array");
     assert_eq!(original, roundtrip);
 }
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_nested_fixed_size() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use glam::*;
+    use pretty_assertions::assert_eq;
+
+    let original: Vec<Vec<[Vec3; 2]>> = vec![
+        vec![[Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)]],
+        vec![],
+        vec![[Vec3::ZERO, Vec3::ONE], [Vec3::X, Vec3::Y]],
+    ];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Vec<[Vec3; 2]>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    let original: Vec<Option<[Mat2; 2]>> = vec![Some([Mat2::IDENTITY, Mat2::ZERO]), None];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Option<[Mat2; 2]>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_integer_quat_affine_roundtrip() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use glam::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    pub struct GlamObj {
+        i1: IVec2,
+        i2: IVec3,
+        i3: IVec4,
+        u1: UVec2,
+        u2: UVec3,
+        u3: UVec4,
+        l1: I64Vec2,
+        l2: I64Vec3,
+        l3: I64Vec4,
+        a: Vec3A,
+        q: Quat,
+        dq: DQuat,
+        t1: Affine2,
+        t2: Affine3A,
+        dt1: DAffine2,
+        dt2: DAffine3,
+    }
+
+    let original: Vec<GlamObj> = vec![GlamObj {
+        i1: IVec2::new(i32::MIN, i32::MAX),
+        i2: IVec3::new(-1, 0, 1),
+        i3: IVec4::new(1, 2, 3, 4),
+        u1: UVec2::new(0, u32::MAX),
+        u2: UVec3::new(1, 2, 3),
+        u3: UVec4::new(1, 2, 3, 4),
+        l1: I64Vec2::new(i64::MIN, i64::MAX),
+        l2: I64Vec3::new(-1, 0, 1),
+        l3: I64Vec4::new(1, 2, 3, 4),
+        a: Vec3A::new(1.0, 2.0, 3.0),
+        q: Quat::from_rotation_z(0.5),
+        dq: DQuat::from_rotation_x(0.25),
+        t1: Affine2::from_scale_angle_translation(Vec2::new(2.0, 3.0), 0.5, Vec2::new(4.0, 5.0)),
+        t2: Affine3A::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+        dt1: DAffine2::from_angle(0.25),
+        dt2: DAffine3::from_scale(DVec3::new(1.0, 2.0, 3.0)),
+    }];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<GlamObj> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_row_major_matrix() {
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray, Float32Array};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::RowMajor;
+    use arrow_convert::serialize::TryIntoArrow;
+    use glam::*;
+    use pretty_assertions::assert_eq;
+
+    // columns [1, 2] and [3, 4], i.e. rows [1, 3] and [2, 4]
+    let original = vec![Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0])];
+
+    let arrow_array: ArrayRef = original.try_into_arrow_as_type::<RowMajor<Mat2>>().unwrap();
+    assert_eq!(
+        arrow_array.data_type(),
+        &<Mat2 as arrow_convert::field::ArrowField>::data_type()
+    );
+    let list = arrow_array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let values = list.values().as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_eq!(values.values().as_ref(), &[1.0, 3.0, 2.0, 4.0]);
+
+    let roundtrip: Vec<Mat2> = arrow_array.try_into_collection_as_type::<RowMajor<Mat2>>().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_struct_layout() {
+    use arrow::array::ArrayRef;
+    use arrow::datatypes::{DataType, Field, Fields};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::StructVec;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use glam::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    pub struct Pose {
+        #[arrow_field(type = "StructVec<Vec3>")]
+        position: Vec3,
+        #[arrow_field(type = "StructVec<Quat>")]
+        rotation: Quat,
+        #[arrow_field(type = "Option<StructVec<IVec2>>")]
+        cell: Option<IVec2>,
+        #[arrow_field(type = "Vec<StructVec<DVec2>>")]
+        path: Vec<DVec2>,
+    }
+
+    let schema = Pose::arrow_schema();
+    assert_eq!(
+        schema.field(0).data_type(),
+        &DataType::Struct(Fields::from(vec![
+            Field::new("x", DataType::Float32, false),
+            Field::new("y", DataType::Float32, false),
+            Field::new("z", DataType::Float32, false),
+        ]))
+    );
+    match schema.field(1).data_type() {
+        DataType::Struct(fields) => assert_eq!(fields[3].name(), "w"),
+        data_type => panic!("unexpected data type {data_type:?}"),
+    }
+
+    let original = vec![
+        Pose {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            rotation: Quat::from_xyzw(0.0, 0.0, 0.0, 1.0),
+            cell: Some(IVec2::new(-4, 5)),
+            path: vec![DVec2::new(0.5, 1.5), DVec2::ZERO],
+        },
+        Pose {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            cell: None,
+            path: vec![],
+        },
+    ];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Pose> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_item_name() {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray};
+    use arrow::compute::cast;
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::ArrowField;
+    use arrow_convert::serialize::TryIntoArrow;
+    use glam::Vec2;
+    use pretty_assertions::assert_eq;
+
+    let original = vec![Vec2::new(1.0, 2.0)];
+    let array: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(
+        array.data_type(),
+        &DataType::FixedSizeList(Arc::new(Field::new("scalar", DataType::Float32, false)), 2)
+    );
+
+    // a column whose child has another name is read after casting it to the expected data type
+    let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let item = Arc::new(Field::new("item", DataType::Float32, false));
+    let renamed: ArrayRef = Arc::new(FixedSizeListArray::new(item, 2, list.values().clone(), None));
+    let result: Result<Vec<Vec2>, _> = renamed.clone().try_into_collection();
+    assert!(result.is_err());
+    let cast = cast(renamed.as_ref(), &<Vec2 as ArrowField>::data_type()).unwrap();
+    let roundtrip: Vec<Vec2> = cast.try_into_collection().unwrap();
+    assert_eq!(roundtrip, original);
+}
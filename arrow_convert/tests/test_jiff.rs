@@ -0,0 +1,131 @@
+//! Tests for the temporal types of the `jiff` crate.
+#[cfg(feature = "jiff")]
+mod jiff_tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, Date32Array};
+    use arrow::datatypes::{DataType, TimeUnit};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{ClampTemporal, NullTemporal};
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use jiff::civil::{date, Date, DateTime};
+    use jiff::tz::{offset, TimeZone};
+    use jiff::{SignedDuration, Timestamp, Zoned};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        at: Timestamp,
+        local: DateTime,
+        day: Option<Date>,
+        elapsed: Vec<SignedDuration>,
+    }
+
+    #[test]
+    fn test_jiff_roundtrip() {
+        let schema = Event::arrow_schema();
+        assert_eq!(
+            schema.field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        assert_eq!(schema.field(2).data_type(), &DataType::Date32);
+        assert_eq!(
+            schema.field(3).data_type(),
+            &DataType::List(
+                arrow::datatypes::Field::new("_item", DataType::Duration(TimeUnit::Nanosecond), false).into()
+            )
+        );
+
+        let original = vec![
+            Event {
+                at: Timestamp::new(1_700_000_000, 123_456_789).unwrap(),
+                local: date(1969, 12, 31).at(23, 59, 59, 500_000_000),
+                day: Some(date(1900, 1, 1)),
+                elapsed: vec![SignedDuration::from_nanos(-5), SignedDuration::from_hours(72)],
+            },
+            Event {
+                at: Timestamp::UNIX_EPOCH,
+                local: date(2262, 4, 11).at(0, 0, 0, 0),
+                day: None,
+                elapsed: vec![],
+            },
+        ];
+
+        let array: ArrayRef = original.try_into_arrow().unwrap();
+        let round_trip: Vec<Event> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_jiff_zoned_loses_time_zone() {
+        assert_eq!(
+            <Zoned as arrow_convert::field::ArrowField>::data_type(),
+            <Timestamp as arrow_convert::field::ArrowField>::data_type()
+        );
+
+        let original = date(2024, 3, 1)
+            .at(12, 30, 0, 0)
+            .to_zoned(TimeZone::fixed(offset(2)))
+            .unwrap();
+        let array: ArrayRef = vec![original.clone()].try_into_arrow().unwrap();
+        let round_trip: Vec<Zoned> = array.try_into_collection().unwrap();
+
+        // the instant survives, the zone and therefore the civil time don't
+        assert_eq!(round_trip[0].timestamp(), original.timestamp());
+        assert_eq!(round_trip[0].time_zone(), &TimeZone::UTC);
+        assert_eq!(round_trip[0].datetime(), date(2024, 3, 1).at(10, 30, 0, 0));
+    }
+
+    #[test]
+    fn test_jiff_date_day_bounds() {
+        let days: ArrayRef = vec![date(1970, 1, 2), date(1969, 12, 31), date(9999, 12, 31), Date::MIN]
+            .try_into_arrow()
+            .unwrap();
+        let values = days.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(&values.values()[..3], &[1, -1, 2_932_896]);
+        let round_trip: Vec<Date> = days.try_into_collection().unwrap();
+        assert_eq!(round_trip[3], Date::MIN);
+
+        // the `i32` day bounds lie far outside the years -9999 to 9999 supported by jiff
+        let array: ArrayRef = Arc::new(Date32Array::from(vec![
+            Some(0),
+            None,
+            Some(i32::MIN),
+            Some(i32::MAX),
+        ]));
+        let result: Result<Vec<Option<Date>>, _> = array.clone().try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid jiff::civil::Date at index 2"), "{err}");
+
+        let array = array.slice(3, 1);
+        let result: Result<Vec<Date>, _> = array.clone().try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid jiff::civil::Date at index 0"), "{err}");
+
+        let nulls: Vec<Option<Date>> = array.try_into_collection_as_type::<NullTemporal<Date>>().unwrap();
+        assert_eq!(nulls, vec![None]);
+    }
+
+    #[test]
+    fn test_jiff_timestamp_bounds() {
+        let values = vec![Timestamp::MAX, Timestamp::MIN];
+        let result: Result<ArrayRef, _> = values.try_into_arrow();
+        assert!(result.is_err());
+
+        // clamped to the bounds of `i64` nanoseconds, which jiff represents exactly
+        let array: ArrayRef = values.try_into_arrow_as_type::<ClampTemporal<Timestamp>>().unwrap();
+        let clamped: Vec<Timestamp> = array.try_into_collection().unwrap();
+        assert_eq!(clamped[0].as_nanosecond(), i64::MAX as i128);
+        assert_eq!(clamped[1].as_nanosecond(), i64::MIN as i128);
+
+        let values = vec![Some(SignedDuration::MAX), Some(SignedDuration::from_secs(1))];
+        let array: ArrayRef = values.try_into_arrow_as_type::<NullTemporal<SignedDuration>>().unwrap();
+        let round_trip: Vec<Option<SignedDuration>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, vec![None, Some(SignedDuration::from_secs(1))]);
+    }
+}
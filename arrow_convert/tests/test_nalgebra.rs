@@ -0,0 +1,90 @@
+#[cfg(feature = "nalgebra")]
+mod nalgebra_tests {
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray, Float64Array, Int32Array};
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use nalgebra::{
+        Isometry3, Matrix2x3, Matrix3, Point2, Point3, SVector, Translation3, UnitQuaternion, Vector2, Vector3,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Body {
+        position: Point3<f64>,
+        velocity: Vector3<f32>,
+        inertia: Matrix3<f64>,
+        orientation: UnitQuaternion<f64>,
+        pose: Isometry3<f32>,
+        cell: Option<Point2<i32>>,
+        samples: Vec<Vector2<u16>>,
+        state: SVector<f32, 6>,
+    }
+
+    #[test]
+    fn test_nalgebra_roundtrip() {
+        let original = vec![
+            Body {
+                position: Point3::new(1.0, 2.0, 3.0),
+                velocity: Vector3::new(0.5, -0.5, 0.0),
+                inertia: Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+                orientation: UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3),
+                pose: Isometry3::from_parts(
+                    Translation3::new(1.0, 2.0, 3.0),
+                    UnitQuaternion::from_euler_angles(0.3, 0.2, 0.1),
+                ),
+                cell: Some(Point2::new(-1, 1)),
+                samples: vec![Vector2::new(1, 2), Vector2::new(3, 4)],
+                state: SVector::from_column_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            },
+            Body {
+                position: Point3::origin(),
+                velocity: Vector3::zeros(),
+                inertia: Matrix3::identity(),
+                orientation: UnitQuaternion::identity(),
+                pose: Isometry3::identity(),
+                cell: None,
+                samples: vec![],
+                state: SVector::zeros(),
+            },
+        ];
+
+        let schema = Body::arrow_schema();
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::FixedSizeList(Field::new("scalar", DataType::Float64, false).into(), 9)
+        );
+        assert_eq!(
+            schema.field(4).data_type(),
+            &DataType::FixedSizeList(Field::new("scalar", DataType::Float32, false).into(), 7)
+        );
+
+        let array: ArrayRef = original.try_into_arrow().unwrap();
+        let round_trip: Vec<Body> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_nalgebra_storage_order() {
+        // matrices are stored column-major
+        let matrices = vec![Matrix2x3::new(1, 2, 3, 4, 5, 6)];
+        let array: ArrayRef = matrices.try_into_arrow().unwrap();
+        let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        let values = list.values().as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(values.values().as_ref(), &[1, 4, 2, 5, 3, 6]);
+        let round_trip: Vec<Matrix2x3<i32>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, matrices);
+
+        // quaternions are stored as [i, j, k, w]
+        let rotations = vec![UnitQuaternion::from_quaternion(nalgebra::Quaternion::new(
+            1.0, 0.0, 0.0, 0.0,
+        ))];
+        let array: ArrayRef = rotations.try_into_arrow().unwrap();
+        let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        let values = list.values().as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(values.values().as_ref(), &[0.0, 0.0, 0.0, 1.0]);
+        let round_trip: Vec<UnitQuaternion<f64>> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, rotations);
+    }
+}
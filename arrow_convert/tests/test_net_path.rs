@@ -0,0 +1,91 @@
+use arrow::array::{Array, ArrayRef, FixedSizeBinaryArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::Ipv4Binary;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Connection {
+    source: Ipv4Addr,
+    #[arrow_field(type = "Ipv4Binary")]
+    gateway: Ipv4Addr,
+    peer: Ipv6Addr,
+    remote: Option<IpAddr>,
+    endpoints: Vec<SocketAddr>,
+    log: PathBuf,
+}
+
+#[test]
+fn test_net_path_round_trip() {
+    let original = vec![
+        Connection {
+            source: Ipv4Addr::new(10, 0, 0, 1),
+            gateway: Ipv4Addr::new(10, 0, 0, 254),
+            peer: Ipv6Addr::LOCALHOST,
+            remote: Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1))),
+            endpoints: vec!["127.0.0.1:8080".parse().unwrap(), "[::1]:443".parse().unwrap()],
+            log: PathBuf::from("/var/log/conn.log"),
+        },
+        Connection {
+            source: Ipv4Addr::UNSPECIFIED,
+            gateway: Ipv4Addr::BROADCAST,
+            peer: "2001:db8::1".parse().unwrap(),
+            remote: None,
+            endpoints: vec![],
+            log: PathBuf::new(),
+        },
+    ];
+
+    let schema = Connection::arrow_schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::UInt32);
+    assert_eq!(schema.field(1).data_type(), &DataType::FixedSizeBinary(4));
+    assert_eq!(schema.field(2).data_type(), &DataType::FixedSizeBinary(16));
+    assert_eq!(schema.field(5).data_type(), &DataType::Utf8);
+
+    let array: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Connection> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_ip_addr_normalized() {
+    let addresses = vec![IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)), IpAddr::V6(Ipv6Addr::LOCALHOST)];
+    let array: ArrayRef = addresses.try_into_arrow().unwrap();
+    let binary = array.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+    assert_eq!(
+        binary.value(0),
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 1, 2, 3, 4]
+    );
+    assert_eq!(binary.value(1), &Ipv6Addr::LOCALHOST.octets());
+
+    // IPv4-mapped addresses come back as IPv4
+    let round_trip: Vec<IpAddr> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, addresses);
+}
+
+#[test]
+fn test_socket_addr_schema() {
+    assert_eq!(
+        <SocketAddr as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("ip", DataType::FixedSizeBinary(16), false),
+            Field::new("port", DataType::UInt16, false),
+        ]))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_path() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let paths = vec![PathBuf::from(OsStr::from_bytes(b"/tmp/\xff"))];
+    let result: Result<ArrayRef, _> = paths.try_into_arrow();
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("is not valid UTF-8"), "{err}");
+}
@@ -0,0 +1,162 @@
+use arrow::array::{Array, ArrayBuilder, ArrayRef, RecordBatch};
+use arrow::buffer::{Buffer, ScalarBuffer};
+use arrow_convert::deserialize::{ArrowArrayIterable, TryIntoCollection};
+use arrow_convert::field::{FixedSizeBinary, FixedSizeVec, LargeBinary, LargeString, LargeVec, I128};
+use arrow_convert::serialize::*;
+use arrow_convert::{deserialize, field, ArrowDeserialize, ArrowField, ArrowSerialize};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use pretty_assertions::assert_eq;
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Row {
+    a: i32,
+    b: Option<String>,
+    c: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+struct Wrapper(u16);
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(type = "dense")]
+enum Dense {
+    A(i32),
+    B(String),
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(type = "sparse")]
+enum Sparse {
+    A,
+    B(bool),
+}
+
+// Serializes `values` interleaved with nulls from `Option<&T>` items and checks that the nulls survive a
+// round trip through `Vec<Option<T>>` deserialization.
+fn check<T>(values: Vec<<T as field::ArrowField>::Type>)
+where
+    T: ArrowSerialize + deserialize::ArrowDeserialize + 'static,
+    <T as field::ArrowField>::Type: Clone + PartialEq + Debug + 'static,
+    <T as ArrowSerialize>::ArrayBuilderType: PushNull,
+    <T as deserialize::ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    let expected = values.into_iter().flat_map(|v| [None, Some(v)]).collect::<Vec<_>>();
+    let array: ArrayRef = expected
+        .iter()
+        .map(Option::as_ref)
+        .try_into_arrow_nullable_as_type::<T>()
+        .unwrap();
+    assert_eq!(array.len(), expected.len());
+    assert_eq!(array.data_type(), &<T as field::ArrowField>::data_type());
+
+    let round_trip: Vec<Option<<T as field::ArrowField>::Type>> =
+        array.try_into_collection_as_type::<Option<T>>().unwrap();
+    assert_eq!(round_trip, expected);
+}
+
+#[test]
+fn test_nullable_primitives() {
+    check::<u8>(vec![1, 2]);
+    check::<i64>(vec![1, -2]);
+    check::<f32>(vec![1.5, 2.5]);
+    check::<half::f16>(vec![half::f16::from_f32(1.0)]);
+    check::<bool>(vec![true, false]);
+    check::<I128<10, 2>>(vec![12345, -1]);
+    check::<NaiveDate>(vec![NaiveDate::from_ymd_opt(2020, 1, 2).unwrap()]);
+    check::<NaiveDateTime>(vec![NaiveDate::from_ymd_opt(2020, 1, 2)
+        .unwrap()
+        .and_hms_opt(3, 4, 5)
+        .unwrap()]);
+    check::<DateTime<Utc>>(vec![DateTime::from_timestamp(1_000_000, 0).unwrap()]);
+}
+
+#[test]
+fn test_nullable_binary_and_strings() {
+    check::<String>(vec!["a".to_string(), "".to_string()]);
+    check::<LargeString>(vec!["a".to_string()]);
+    check::<Vec<u8>>(vec![vec![1, 2], vec![]]);
+    check::<LargeBinary>(vec![vec![1, 2]]);
+    check::<FixedSizeBinary<2>>(vec![vec![1, 2]]);
+    check::<[u8; 3]>(vec![[1, 2, 3]]);
+    check::<Buffer>(vec![Buffer::from_vec(vec![1_u8, 2])]);
+}
+
+#[test]
+fn test_nullable_lists() {
+    check::<Vec<i32>>(vec![vec![1, 2], vec![]]);
+    check::<Vec<Option<String>>>(vec![vec![Some("a".to_string()), None]]);
+    check::<LargeVec<i32>>(vec![vec![1]]);
+    check::<FixedSizeVec<i32, 2>>(vec![vec![1, 2]]);
+    check::<[i16; 2]>(vec![[1, 2]]);
+    check::<[Row; 1]>(vec![[Row {
+        a: 1,
+        b: None,
+        c: vec![],
+    }]]);
+    check::<VecDeque<u16>>(vec![VecDeque::from([1, 2])]);
+    check::<BTreeSet<u16>>(vec![BTreeSet::from([1, 2])]);
+    check::<ScalarBuffer<u32>>(vec![ScalarBuffer::from(vec![1_u32, 2])]);
+}
+
+#[test]
+fn test_nullable_structs_and_enums() {
+    check::<Row>(vec![
+        Row {
+            a: 1,
+            b: Some("x".to_string()),
+            c: vec![1],
+        },
+        Row {
+            a: 2,
+            b: None,
+            c: vec![],
+        },
+    ]);
+    check::<Wrapper>(vec![Wrapper(1)]);
+    check::<(i32, String)>(vec![(1, "a".to_string())]);
+    check::<Dense>(vec![Dense::A(1), Dense::B("b".to_string())]);
+    check::<Sparse>(vec![Sparse::A, Sparse::B(true)]);
+}
+
+#[test]
+fn test_nullable_record_batch() {
+    let rows = [
+        Some(Row {
+            a: 1,
+            b: None,
+            c: vec![],
+        }),
+        None,
+    ];
+    let batch: RecordBatch = rows.iter().map(Option::as_ref).try_into_arrow_nullable().unwrap();
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(batch.column(0).null_count(), 1);
+    assert!(batch.schema().field(0).is_nullable());
+
+    // the builder used by the derive for `Option<Row>` produces the same layout
+    let expected: ArrayRef = rows.try_into_arrow().unwrap();
+    assert_eq!(batch.column(0).to_data(), expected.to_data());
+
+    let mut builder = arrow_serialize_nullable_to_mutable_array::<Row, Row, _>([None, None]).unwrap();
+    assert_eq!(builder.finish().null_count(), 2);
+}
+
+#[test]
+fn test_nullable_features() {
+    #[cfg(feature = "glam")]
+    {
+        check::<glam::Vec2>(vec![glam::Vec2::new(1.0, 2.0)]);
+        check::<glam::Mat2>(vec![glam::Mat2::IDENTITY]);
+    }
+    #[cfg(feature = "rust_decimal")]
+    check::<rust_decimal::Decimal>(vec![rust_decimal::Decimal::new(12345, 2)]);
+    #[cfg(feature = "smallvec")]
+    check::<smallvec::SmallVec<[i32; 2]>>(vec![smallvec::smallvec![1, 2, 3]]);
+    #[cfg(feature = "tinystr")]
+    check::<tinystr::TinyAsciiStr<4>>(vec![tinystr::tinystr!(4, "abcd")]);
+    #[cfg(feature = "uuid")]
+    check::<uuid::Uuid>(vec![uuid::Uuid::from_u128(42)]);
+}
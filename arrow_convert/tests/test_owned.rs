@@ -0,0 +1,86 @@
+use arrow::array::{Array, ArrayRef, RecordBatch};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::LargeString;
+use arrow_convert::serialize::*;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Row {
+    id: u64,
+    name: String,
+    values: Vec<i32>,
+    point: (f32, f32),
+}
+
+fn make_row(id: u64) -> Row {
+    Row {
+        id,
+        name: format!("row {id}"),
+        values: (0..id as i32 % 3).collect(),
+        point: (id as f32, -(id as f32)),
+    }
+}
+
+#[test]
+fn test_owned_iterator() {
+    let array: ArrayRef = (0..5).map(make_row).try_into_arrow_owned().unwrap();
+    let expected: ArrayRef = (0..5).map(make_row).collect::<Vec<_>>().try_into_arrow().unwrap();
+    assert_eq!(array.to_data(), expected.to_data());
+
+    let round_trip: Vec<Row> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, (0..5).map(make_row).collect::<Vec<_>>());
+
+    let batch: RecordBatch = (0..5).map(make_row).try_into_arrow_owned().unwrap();
+    assert_eq!(batch.num_rows(), 5);
+}
+
+#[test]
+fn test_owned_option_iterator() {
+    let array: ArrayRef = (0..6)
+        .map(|id| (id % 2 == 0).then(|| make_row(id)))
+        .try_into_arrow_owned()
+        .unwrap();
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.null_count(), 3);
+
+    let round_trip: Vec<Option<Row>> = array.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        (0..6).map(|id| (id % 2 == 0).then(|| make_row(id))).collect::<Vec<_>>()
+    );
+
+    let array: ArrayRef = ["a", "b"]
+        .into_iter()
+        .map(|x| Some(x.to_string()))
+        .chain([None])
+        .try_into_arrow_owned_as_type::<Option<LargeString>>()
+        .unwrap();
+    assert_eq!(
+        array.data_type(),
+        &<Option<LargeString> as arrow_convert::field::ArrowField>::data_type()
+    );
+    assert_eq!(array.null_count(), 1);
+}
+
+#[test]
+fn test_capacity_from_size_hint() {
+    let builder = arrow_serialize_owned_to_mutable_array::<u32, u32, _>(0..1000_u32).unwrap();
+    assert!(builder.capacity() >= 1000);
+
+    let values = vec![1.5_f64; 100];
+    let builder = arrow_serialize_to_mutable_array::<f64, f64, _>(&values).unwrap();
+    assert!(builder.capacity() >= 100);
+
+    let builder = <[u8; 4] as ArrowSerialize>::new_array_with_capacity(10);
+    assert_eq!(arrow::array::ArrayBuilder::len(&builder), 0);
+
+    // builders created with a capacity behave the same as the default ones
+    let mut builder = <Row as ArrowSerialize>::new_array_with_capacity(3);
+    for row in (0..3).map(make_row) {
+        <Row as ArrowSerialize>::arrow_serialize(&row, &mut builder).unwrap();
+    }
+    let array = arrow::array::ArrayBuilder::finish(&mut builder);
+    let expected: ArrayRef = (0..3).map(make_row).try_into_arrow_owned().unwrap();
+    assert_eq!(array.to_data(), expected.to_data());
+}
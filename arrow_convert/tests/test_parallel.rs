@@ -0,0 +1,83 @@
+#[cfg(feature = "rayon")]
+mod parallel {
+    use arrow::array::{Array, ArrayRef};
+    use arrow_convert::deserialize::*;
+    use arrow_convert::field::LargeString;
+    use arrow_convert::serialize::*;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: u64,
+        name: Option<String>,
+        values: Vec<f32>,
+    }
+
+    fn rows(n: u64) -> Vec<Row> {
+        (0..n)
+            .map(|id| Row {
+                id,
+                name: (id % 3 != 0).then(|| format!("row {id}")),
+                values: (0..id % 4).map(|x| x as f32).collect(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let original = rows(10_000);
+
+        let sequential: ArrayRef = original.try_into_arrow().unwrap();
+        let parallel: ArrayRef = original.try_into_arrow_par().unwrap();
+        assert_eq!(parallel.to_data(), sequential.to_data());
+
+        let round_trip: Vec<Row> = parallel.try_into_vec_par().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_parallel_small_chunks() {
+        let original = rows(1_001);
+
+        // chunk sizes that don't divide the length exercise the last partial chunk
+        let array = arrow_serialize_parallel::<Row, Row>(&original, 7).unwrap();
+        assert_eq!(array.len(), 1_001);
+
+        let round_trip = arrow_array_deserialize_parallel::<Row, Row>(array.as_ref(), 13).unwrap();
+        assert_eq!(round_trip, original);
+
+        // slices are supported too
+        let sliced = array.slice(500, 300);
+        let round_trip = arrow_array_deserialize_parallel::<Row, Row>(sliced.as_ref(), 64).unwrap();
+        assert_eq!(round_trip, original[500..800]);
+    }
+
+    #[test]
+    fn test_parallel_empty_and_nulls() {
+        let empty: Vec<Option<Row>> = vec![];
+        let array: ArrayRef = empty.try_into_arrow_par().unwrap();
+        assert_eq!(array.len(), 0);
+        assert_eq!(
+            array.data_type(),
+            &<Option<Row> as arrow_convert::field::ArrowField>::data_type()
+        );
+        let round_trip: Vec<Option<Row>> = array.try_into_vec_par().unwrap();
+        assert!(round_trip.is_empty());
+
+        let original = (0..100)
+            .map(|x| (x % 2 == 0).then(|| x.to_string()))
+            .collect::<Vec<_>>();
+        let array = arrow_serialize_parallel::<_, Option<LargeString>>(&original, 10).unwrap();
+        assert_eq!(array.null_count(), 50);
+        let round_trip: Vec<Option<String>> = array.try_into_vec_par_as_type::<Option<LargeString>>().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_parallel_type_mismatch() {
+        let array: ArrayRef = [1_i32, 2, 3].try_into_arrow_par().unwrap();
+        let result: Result<Vec<String>, _> = array.try_into_vec_par();
+        assert!(result.is_err());
+    }
+}
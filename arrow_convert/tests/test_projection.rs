@@ -0,0 +1,208 @@
+use arrow::array::{Array, ArrayRef, RecordBatch};
+use arrow_convert::deserialize::{arrow_array_project, record_batch_project, TryIntoCollection};
+use arrow_convert::serialize::{FlattenRecordBatch, TryIntoArrow};
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Order {
+    id: u64,
+    venue: String,
+    px: f64,
+    qty: Option<u32>,
+    legs: Vec<Leg>,
+    meta: Meta,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Leg {
+    symbol: String,
+    ratio: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Meta {
+    source: String,
+    seq: u64,
+}
+
+fn orders() -> Vec<Option<Order>> {
+    vec![
+        Some(Order {
+            id: 1,
+            venue: "X".to_string(),
+            px: 1.5,
+            qty: Some(10),
+            legs: vec![
+                Leg {
+                    symbol: "A".to_string(),
+                    ratio: 1,
+                },
+                Leg {
+                    symbol: "B".to_string(),
+                    ratio: -1,
+                },
+            ],
+            meta: Meta {
+                source: "feed".to_string(),
+                seq: 7,
+            },
+        }),
+        None,
+        Some(Order {
+            id: 3,
+            venue: "Y".to_string(),
+            px: 3.5,
+            qty: None,
+            legs: vec![],
+            meta: Meta {
+                source: "manual".to_string(),
+                seq: 9,
+            },
+        }),
+    ]
+}
+
+#[test]
+fn test_project_struct_array() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct OrderSummary {
+        px: f64,
+        id: u64,
+    }
+
+    let b: ArrayRef = orders().try_into_arrow().unwrap();
+    let projected = arrow_array_project::<Option<OrderSummary>>(b.as_ref()).unwrap();
+    assert_eq!(projected.null_count(), 1);
+
+    let round_trip: Vec<Option<OrderSummary>> = projected.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Some(OrderSummary { px: 1.5, id: 1 }),
+            None,
+            Some(OrderSummary { px: 3.5, id: 3 })
+        ]
+    );
+
+    // slices keep their offset
+    let projected = arrow_array_project::<Option<OrderSummary>>(b.slice(2, 1).as_ref()).unwrap();
+    let round_trip: Vec<Option<OrderSummary>> = projected.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Some(OrderSummary { px: 3.5, id: 3 })]);
+}
+
+#[test]
+fn test_project_nested() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct LegSymbol {
+        symbol: String,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct MetaSeq {
+        seq: u64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Narrow {
+        #[arrow_field(name = "legs")]
+        leg_symbols: Vec<LegSymbol>,
+        meta: MetaSeq,
+        qty: Option<u32>,
+    }
+
+    let b: ArrayRef = orders()
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .try_into_arrow()
+        .unwrap();
+    let round_trip: Vec<Narrow> = arrow_array_project::<Narrow>(b.as_ref())
+        .unwrap()
+        .try_into_collection()
+        .unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Narrow {
+                leg_symbols: vec![
+                    LegSymbol {
+                        symbol: "A".to_string()
+                    },
+                    LegSymbol {
+                        symbol: "B".to_string()
+                    }
+                ],
+                meta: MetaSeq { seq: 7 },
+                qty: Some(10),
+            },
+            Narrow {
+                leg_symbols: vec![],
+                meta: MetaSeq { seq: 9 },
+                qty: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_project_record_batch() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct VenuePx {
+        venue: String,
+        px: f64,
+    }
+
+    let original = orders().into_iter().flatten().collect::<Vec<_>>();
+    let batch: RecordBatch = original.try_into_arrow().unwrap();
+    let batch = batch.flatten().unwrap();
+    assert_eq!(batch.num_columns(), 6);
+
+    let round_trip: Vec<VenuePx> = record_batch_project::<VenuePx>(&batch)
+        .unwrap()
+        .try_into_collection()
+        .unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            VenuePx {
+                venue: "X".to_string(),
+                px: 1.5
+            },
+            VenuePx {
+                venue: "Y".to_string(),
+                px: 3.5
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_project_errors() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Missing {
+        id: u64,
+        missing: bool,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct WrongType {
+        id: String,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct NotNullable {
+        qty: u32,
+    }
+
+    let b: ArrayRef = orders().try_into_arrow().unwrap();
+
+    let err = arrow_array_project::<Missing>(b.as_ref()).unwrap_err();
+    assert!(err.to_string().contains("field 'missing' not found"), "{err}");
+
+    let err = arrow_array_project::<WrongType>(b.as_ref()).unwrap_err();
+    assert!(err.to_string().contains("field 'id'"), "{err}");
+
+    // a nullable column can't be projected onto a non-nullable field if it contains nulls
+    assert!(arrow_array_project::<NotNullable>(b.as_ref()).is_err());
+}
@@ -147,6 +147,94 @@ fn test_fixed_size_vec() {
     assert_eq!(round_trip, ints);
 }
 
+#[test]
+fn test_nested_fixed_size_vec() {
+    let ints = vec![vec![vec![1, 2], vec![3, 4], vec![5, 6]]];
+    let b: ArrayRef = ints
+        .try_into_arrow_as_type::<FixedSizeVec<FixedSizeVec<i32, 2>, 3>>()
+        .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::FixedSizeList(
+            Arc::new(Field::new(
+                DEFAULT_FIELD_NAME,
+                DataType::FixedSizeList(
+                    Arc::new(Field::new(DEFAULT_FIELD_NAME, DataType::Int32, false)),
+                    2
+                ),
+                false
+            )),
+            3
+        )
+    );
+    let round_trip: Vec<Vec<Vec<i32>>> = b
+        .try_into_collection_as_type::<FixedSizeVec<FixedSizeVec<i32, 2>, 3>>()
+        .unwrap();
+    assert_eq!(round_trip, ints);
+}
+
+#[test]
+fn test_nested_fixed_size_array() {
+    let original = vec![[[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], [[0.0; 3]; 3]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<[[f32; 3]; 3] as arrow_convert::field::ArrowField>::data_type()
+    );
+    let round_trip: Vec<[[f32; 3]; 3]> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // list of fixed size lists
+    let original = vec![vec![[1_i64, 2], [3, 4]], vec![], vec![[5, 6]]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Vec<[i64; 2]>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // fixed size list of fixed size binaries
+    let original = vec![[[1_u8, 2, 3, 4], [5, 6, 7, 8]]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<[[u8; 4]; 2]> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_nested_fixed_size_array_nulls() {
+    let original = vec![
+        Some([Some([1_i16, 2]), None, Some([5, 6])]),
+        None,
+        Some([None, None, None]),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+
+    let outer = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    assert_eq!(outer.values().len(), 9);
+    assert_eq!(outer.values().null_count(), 7);
+
+    let round_trip: Vec<Option<[Option<[i16; 2]>; 3]>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Grid {
+        cells: Option<[[u32; 2]; 2]>,
+        rows: Vec<Option<[u32; 2]>>,
+    }
+
+    let original = vec![
+        Grid {
+            cells: Some([[1, 2], [3, 4]]),
+            rows: vec![Some([1, 2]), None],
+        },
+        Grid {
+            cells: None,
+            rows: vec![None],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Grid> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
 #[test]
 fn test_primitive_type_vec() {
     macro_rules! test_int_type {
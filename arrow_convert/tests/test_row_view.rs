@@ -0,0 +1,177 @@
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow_convert::deserialize::RowView;
+use arrow_convert::field::LargeString;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(row_view)]
+struct Trade {
+    symbol: String,
+    price: f64,
+    size: Option<u32>,
+    #[arrow_field(type = "LargeString")]
+    venue: String,
+    fills: Vec<Fill>,
+    #[arrow_field(skip)]
+    note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(row_view)]
+struct Fill(i64, f32);
+
+fn trades() -> Vec<Trade> {
+    vec![
+        Trade {
+            symbol: "AAA".to_string(),
+            price: 1.5,
+            size: Some(10),
+            venue: "X".to_string(),
+            fills: vec![Fill(1, 0.5), Fill(2, 1.0)],
+            note: String::new(),
+        },
+        Trade {
+            symbol: "BBB".to_string(),
+            price: 2.5,
+            size: None,
+            venue: "Y".to_string(),
+            fills: vec![],
+            note: String::new(),
+        },
+    ]
+}
+
+#[test]
+fn test_row_view_accessors() {
+    let original = trades();
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let array = b.as_struct();
+
+    let row = TradeArray::row(array, 1).unwrap();
+    assert_eq!(row.row_index(), 1);
+    assert!(!row.row_is_null());
+    assert_eq!(row.symbol(), "BBB");
+    assert_eq!(row.price(), 2.5);
+    assert_eq!(row.size(), None);
+    assert_eq!(row.venue(), "Y");
+    assert_eq!(row.fills(), vec![]);
+    assert_eq!(row.row_to_owned(), Some(original[1].clone()));
+
+    let row = TradeArray::row(array, 0).unwrap();
+    assert_eq!(row.size(), Some(10));
+    let fills = row.fills();
+    assert_eq!(fills, vec![Fill(1, 0.5), Fill(2, 1.0)]);
+
+    // tuple struct fields are exposed as `field_{i}`
+    let fills: ArrayRef = fills.try_into_arrow().unwrap();
+    let fill = FillArray::row(fills.as_struct(), 1).unwrap();
+    assert_eq!((fill.field_0(), fill.field_1()), (2, 1.0));
+}
+
+#[test]
+fn test_row_view_scan() {
+    let original = trades();
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let total: f64 = TradeArray::rows(b.as_struct()).unwrap().map(|row| row.price()).sum();
+    assert_eq!(total, 4.0);
+
+    let symbols = TradeArray::rows(b.slice(1, 1).as_struct())
+        .unwrap()
+        .map(|row| row.symbol())
+        .collect::<Vec<_>>();
+    assert_eq!(symbols, vec!["BBB"]);
+}
+
+#[test]
+fn test_row_view_nulls() {
+    let original = vec![None, Some(trades().remove(0))];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let rows = TradeArray::rows(b.as_struct()).unwrap().collect::<Vec<_>>();
+    assert!(rows[0].row_is_null());
+    assert_eq!(rows[0].row_to_owned(), None);
+    assert!(!rows[1].row_is_null());
+    assert_eq!(rows[1].symbol(), "AAA");
+}
+
+#[test]
+fn test_row_view_checks_array() {
+    let b: ArrayRef = trades().try_into_arrow().unwrap();
+    let err = TradeArray::row(b.as_struct(), 2).unwrap_err().to_string();
+    assert!(err.contains("row index 2 out of bounds"), "{err}");
+
+    // arrays of another struct are rejected up front rather than panicking in the accessors
+    let fills: ArrayRef = vec![Fill(1, 0.5)].try_into_arrow().unwrap();
+    assert!(TradeArray::row(fills.as_struct(), 0).is_err());
+    assert!(TradeArray::rows(fills.as_struct()).is_err());
+}
+
+#[test]
+fn test_row_view_custom_name() {
+    // a field named like a `RowView` method shadows it, the method stays reachable through the trait
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(row_view = "EntryView")]
+    struct Entry {
+        row_index: u32,
+        label: String,
+    }
+
+    let original = vec![
+        Entry {
+            row_index: 7,
+            label: "a".to_string(),
+        },
+        Entry {
+            row_index: 9,
+            label: "b".to_string(),
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let row: EntryView = EntryArray::row(b.as_struct(), 1).unwrap();
+    assert_eq!(row.row_index(), 9);
+    assert_eq!(RowView::row_index(&row), 1);
+    assert_eq!(row.label(), "b");
+    assert_eq!(row.row_to_owned(), Some(original[1].clone()));
+}
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_row_view_invalid_values() {
+    use std::sync::Arc;
+
+    use arrow::array::{FixedSizeBinaryArray, StructArray};
+    use arrow::datatypes::Field;
+    use tinystr::TinyAsciiStr;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    #[arrow_field(row_view)]
+    struct Locale {
+        language: TinyAsciiStr<2>,
+    }
+
+    let values =
+        FixedSizeBinaryArray::try_from_iter(vec![b"en".as_slice(), [0xFF, b'a'].as_slice()].into_iter()).unwrap();
+    let array = StructArray::from(vec![(
+        Arc::new(Field::new("language", values.data_type().clone(), false)),
+        Arc::new(values) as ArrayRef,
+    )]);
+
+    let err = LocaleArray::row(&array, 0).unwrap_err().to_string();
+    assert!(
+        err.contains("Locale.language: invalid TinyAsciiStr<2> at index 1"),
+        "{err}"
+    );
+    assert!(LocaleArray::rows(&array).is_err());
+
+    let valid = array.slice(0, 1);
+    let row = LocaleArray::row(&valid, 0).unwrap();
+    assert_eq!(
+        row.row_to_owned(),
+        Some(Locale {
+            language: "en".parse().unwrap()
+        })
+    );
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow::datatypes::DataType;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::Json;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        kind: String,
+        count: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        id: u64,
+        attributes: Value,
+        payload: Option<Json<Payload>>,
+        tags: Vec<Value>,
+    }
+
+    #[test]
+    fn test_value_round_trip() {
+        let values = vec![json!({"a": 1, "b": [true, null]}), json!("text"), Value::Null];
+        let array: ArrayRef = values.try_into_arrow().unwrap();
+        assert_eq!(array.data_type(), &DataType::Utf8);
+        let strings = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(strings.value(0), r#"{"a":1,"b":[true,null]}"#);
+        assert_eq!(strings.value(2), "null");
+
+        let round_trip: Vec<Value> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, values);
+    }
+
+    #[test]
+    fn test_extension_type() {
+        let field = <Value as arrow_convert::field::ArrowField>::field("v");
+        assert_eq!(field.extension_type_name(), Some("arrow.json"));
+        let field = <Option<Json<Payload>> as arrow_convert::field::ArrowField>::field("v");
+        assert_eq!(field.extension_type_name(), Some("arrow.json"));
+        assert!(field.is_nullable());
+
+        let schema = Event::arrow_schema();
+        assert_eq!(schema.field(1).extension_type_name(), Some("arrow.json"));
+        assert_eq!(schema.field(2).extension_type_name(), Some("arrow.json"));
+    }
+
+    #[test]
+    fn test_struct_round_trip() {
+        let events = vec![
+            Event {
+                id: 1,
+                attributes: json!({"source": "api"}),
+                payload: Some(Json(Payload {
+                    kind: "click".to_string(),
+                    count: 3,
+                })),
+                tags: vec![json!("x"), json!(2)],
+            },
+            Event {
+                id: 2,
+                attributes: json!([]),
+                payload: None,
+                tags: vec![],
+            },
+        ];
+        let array: ArrayRef = events.try_into_arrow().unwrap();
+        let round_trip: Vec<Event> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, events);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![Some("{}"), None, Some("{not json")]));
+        let result: Result<Vec<Option<Value>>, _> = array.clone().try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid JSON at index 2"), "{err}");
+
+        // text that doesn't parse into a `Value` is rejected up front rather than panicking during iteration
+        let nested = format!("{}{}", "[".repeat(200), "]".repeat(200));
+        for text in ["{} x", "", nested.as_str()] {
+            let array: ArrayRef = Arc::new(StringArray::from(vec![Some("1"), Some(text)]));
+            let result: Result<Vec<Value>, _> = array.try_into_collection();
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains("invalid JSON at index 1"), "{err}");
+        }
+
+        // valid JSON that doesn't match the target type is also an error
+        let array: ArrayRef = Arc::new(StringArray::from(vec![r#"{"kind":"a","count":-1}"#]));
+        let result: Result<Vec<Json<Payload>>, _> = array.try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid JSON at index 0"), "{err}");
+    }
+}
@@ -90,3 +90,98 @@ fn test_nested_slice() {
         assert_eq!(round_trip, original_slice);
     }
 }
+
+#[test]
+fn test_children_built_with_declared_fields() {
+    use arrow_convert::field::{FixedSizeVec, LargeVec};
+    use std::collections::VecDeque;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    #[arrow_field(list_element_name = "element", list_element_metadata(scope = "container"))]
+    struct Annotated {
+        plain: Vec<i32>,
+        #[arrow_field(list_element_name = "node")]
+        renamed: Option<Vec<String>>,
+        #[arrow_field(type = "LargeVec<i64>", list_element_metadata(scope = "field"))]
+        large: Vec<i64>,
+        #[arrow_field(type = "FixedSizeVec<u32, 2>")]
+        fixed: Vec<u32>,
+        array: [i16; 2],
+        nested: Vec<Vec<u16>>,
+        deque: VecDeque<f32>,
+    }
+
+    let original = vec![
+        Annotated {
+            plain: vec![1, 2],
+            renamed: Some(vec!["a".to_string()]),
+            large: vec![3],
+            fixed: vec![4, 5],
+            array: [6, 7],
+            nested: vec![vec![8], vec![]],
+            deque: VecDeque::from([1.5]),
+        },
+        Annotated {
+            plain: vec![],
+            renamed: None,
+            large: vec![],
+            fixed: vec![0, 0],
+            array: [0, 0],
+            nested: vec![],
+            deque: VecDeque::new(),
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<Annotated as arrow_convert::field::ArrowField>::data_type()
+    );
+
+    let round_trip: Vec<Annotated> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the builder can also be finished directly without panicking
+    let mut builder = <Annotated as ArrowSerialize>::new_array();
+    <Annotated as ArrowSerialize>::arrow_serialize(&original[0], &mut builder).unwrap();
+    let cloned = builder.try_finish_cloned().unwrap();
+    let finished = builder.try_finish().unwrap();
+    assert_eq!(cloned.to_data(), finished.to_data());
+    assert_eq!(finished.data_type(), b.data_type());
+}
+
+#[test]
+fn test_try_finish_mismatched_child() {
+    /// Declares `Int64` but builds `Int32` arrays.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Mismatched(i32);
+
+    impl arrow_convert::field::ArrowField for Mismatched {
+        type Type = Self;
+
+        fn data_type() -> arrow::datatypes::DataType {
+            arrow::datatypes::DataType::Int64
+        }
+    }
+
+    impl ArrowSerialize for Mismatched {
+        type ArrayBuilderType = Int32Builder;
+
+        fn new_array() -> Self::ArrayBuilderType {
+            Self::ArrayBuilderType::default()
+        }
+
+        fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+            array.append_value(v.0);
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize)]
+    struct Outer {
+        value: Mismatched,
+    }
+
+    let result: arrow::error::Result<ArrayRef> = [Outer { value: Mismatched(1) }].try_into_arrow();
+    assert!(result.is_err());
+}
@@ -0,0 +1,276 @@
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::{DataType, Field};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{Tensor, TensorShape};
+use arrow_convert::serialize::TryIntoArrow;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_fixed_size_array_tensor() {
+    let values: Vec<[[f32; 2]; 3]> = vec![[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], [[0.0; 2]; 3]];
+
+    let array: ArrayRef = values.try_into_arrow_as_type::<Tensor<[[f32; 2]; 3]>>().unwrap();
+    assert_eq!(
+        array.data_type(),
+        &DataType::FixedSizeList(Field::new("_item", DataType::Float32, false).into(), 6)
+    );
+
+    let round_trip: Vec<[[f32; 2]; 3]> = array.try_into_collection_as_type::<Tensor<[[f32; 2]; 3]>>().unwrap();
+    assert_eq!(round_trip, values);
+}
+
+#[test]
+fn test_tensor_extension_metadata() {
+    struct Patch;
+
+    impl TensorShape for Patch {
+        fn shape() -> Vec<usize> {
+            vec![2, 4]
+        }
+
+        fn dim_names() -> Option<Vec<String>> {
+            Some(vec!["H".to_string(), "W".to_string()])
+        }
+    }
+
+    let field = <Tensor<[[u16; 4]; 2]> as arrow_convert::field::ArrowField>::field("t");
+    assert_eq!(field.extension_type_name(), Some("arrow.fixed_shape_tensor"));
+    assert_eq!(field.extension_type_metadata(), Some(r#"{"shape":[2,4]}"#));
+
+    let field = <Option<Tensor<[[u16; 4]; 2], Patch>> as arrow_convert::field::ArrowField>::field("t");
+    assert!(field.is_nullable());
+    assert_eq!(
+        field.extension_type_metadata(),
+        Some(r#"{"shape":[2,4],"dim_names":["H","W"]}"#)
+    );
+
+    struct Escaped;
+
+    impl TensorShape for Escaped {
+        fn shape() -> Vec<usize> {
+            vec![2, 4]
+        }
+
+        fn dim_names() -> Option<Vec<String>> {
+            Some(vec!["\u{1b}[1m\"H\"".to_string(), "W\\".to_string()])
+        }
+
+        fn permutation() -> Option<Vec<usize>> {
+            Some(vec![1, 0])
+        }
+    }
+
+    // dimension names are escaped as JSON strings, not with Rust `Debug` escapes
+    let field = <Tensor<[[u16; 4]; 2], Escaped> as arrow_convert::field::ArrowField>::field("t");
+    let metadata = field.extension_type_metadata().unwrap();
+    assert_eq!(
+        metadata,
+        r#"{"shape":[2,4],"dim_names":["\u001b[1m\"H\"","W\\"],"permutation":[1,0]}"#
+    );
+    let parsed: serde_json::Value = serde_json::from_str(metadata).unwrap();
+    assert_eq!(parsed["dim_names"][0], "\u{1b}[1m\"H\"");
+    assert_eq!(parsed["dim_names"][1], "W\\");
+}
+
+#[test]
+fn test_tensor_layout_mismatch() {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow::datatypes::Schema;
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::deserialize::{record_batch_project, FieldArray, TypedArray};
+    use arrow_convert::{ArrowDeserialize, ArrowField};
+
+    #[derive(Debug, ArrowField, ArrowDeserialize)]
+    struct Row {
+        #[arrow_field(type = "Tensor<[[f32; 2]; 3]>")]
+        t: [[f32; 2]; 3],
+    }
+
+    let values: Vec<[[f32; 2]; 3]> = vec![[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]];
+    let column: ArrayRef = values.try_into_arrow_as_type::<Tensor<[[f32; 2]; 3]>>().unwrap();
+    let field = |metadata: &str| {
+        Field::new("t", column.data_type().clone(), false).with_metadata(HashMap::from([
+            (
+                "ARROW:extension:name".to_string(),
+                "arrow.fixed_shape_tensor".to_string(),
+            ),
+            ("ARROW:extension:metadata".to_string(), metadata.to_string()),
+        ]))
+    };
+    let batch = |field: Field| RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![column.clone()]).unwrap();
+
+    // other producers may format the metadata differently and spell out the identity permutation
+    let same = field(r#"{ "dim_names": ["x", "y"], "shape": [3, 2], "permutation": [0, 1] }"#);
+    let round_trip: Vec<[[f32; 2]; 3]> = FieldArray::new(&same, column.clone())
+        .try_into_collection_as_type::<Tensor<[[f32; 2]; 3]>>()
+        .unwrap();
+    assert_eq!(round_trip, values);
+    assert!(TypedArray::<Tensor<[[f32; 2]; 3]>>::try_new_with_field(&same, column.clone()).is_ok());
+    let rows: Vec<Row> = record_batch_project::<Row>(&batch(same))
+        .unwrap()
+        .try_into_collection()
+        .unwrap();
+    assert_eq!(rows[0].t, values[0]);
+
+    // same number of elements, different layout
+    for (metadata, expected) in [
+        (
+            r#"{"shape":[2,3]}"#,
+            "but was shape=[2, 3] and permutation=[0, 1]",
+        ),
+        (
+            r#"{"shape":[3,2],"permutation":[1,0]}"#,
+            "but was shape=[3, 2] and permutation=[1, 0]",
+        ),
+        (r#"{"shape":[6]}"#, "but was shape=[6] and permutation=[0]"),
+    ] {
+        let other = field(metadata);
+        let result: Result<Vec<[[f32; 2]; 3]>, _> =
+            FieldArray::new(&other, column.clone()).try_into_collection_as_type::<Tensor<[[f32; 2]; 3]>>();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Tensor layout mismatch for field 't'"), "{err}");
+        assert!(err.contains(expected), "{err}");
+        assert!(TypedArray::<Tensor<[[f32; 2]; 3]>>::try_new_with_field(&other, column.clone()).is_err());
+        let err = record_batch_project::<Row>(&batch(other)).unwrap_err().to_string();
+        assert!(err.contains(expected), "{err}");
+    }
+
+    let invalid = field(r#"{"dim_names":["x","y"]}"#);
+    let err = TypedArray::<Tensor<[[f32; 2]; 3]>>::try_new_with_field(&invalid, column.clone()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid arrow.fixed_shape_tensor metadata for field 't': missing shape"),
+        "{err}"
+    );
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray, Float32Array, Int32Array, ListArray, StructArray};
+    use arrow::buffer::OffsetBuffer;
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{Shape2, Tensor, VariableShapeTensor};
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use ndarray::{array, Array2, ArrayD, IxDyn};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Sample {
+        id: u32,
+        #[arrow_field(type = "Tensor<Array2<f32>, Shape2<2, 3>>")]
+        features: Array2<f32>,
+        #[arrow_field(type = "Option<Tensor<Array2<f32>, Shape2<2, 3>>>")]
+        mask: Option<Array2<f32>>,
+    }
+
+    #[test]
+    fn test_ndarray_tensor_round_trip() {
+        let samples = vec![
+            Sample {
+                id: 1,
+                features: array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                mask: None,
+            },
+            Sample {
+                id: 2,
+                // column-major arrays are stored in logical row-major order
+                features: array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]].reversed_axes(),
+                mask: Some(Array2::ones((2, 3))),
+            },
+        ];
+
+        let schema = Sample::arrow_schema();
+        assert_eq!(
+            schema.field(1).extension_type_name(),
+            Some("arrow.fixed_shape_tensor")
+        );
+        assert_eq!(
+            schema.field(1).extension_type_metadata(),
+            Some(r#"{"shape":[2,3]}"#)
+        );
+
+        let array: ArrayRef = samples.try_into_arrow().unwrap();
+        let round_trip: Vec<Sample> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, samples);
+    }
+
+    #[test]
+    fn test_ndarray_tensor_shape_mismatch() {
+        let values = vec![Array2::<f32>::zeros((3, 2))];
+        let result: Result<ArrayRef, _> = values.try_into_arrow_as_type::<Tensor<Array2<f32>, Shape2<2, 3>>>();
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.contains("does not match the declared shape [2, 3]"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_variable_shape_tensor_round_trip() {
+        let values: Vec<Option<ArrayD<f32>>> = vec![
+            Some(ArrayD::from_shape_vec(IxDyn(&[1, 2]), vec![1.0, 2.0]).unwrap()),
+            None,
+            Some(ArrayD::from_shape_vec(IxDyn(&[3, 1]), vec![3.0, 4.0, 5.0]).unwrap()),
+        ];
+
+        let array: ArrayRef = values
+            .try_into_arrow_as_type::<Option<VariableShapeTensor<ArrayD<f32>, 2>>>()
+            .unwrap();
+        let field = <VariableShapeTensor<ArrayD<f32>, 2> as arrow_convert::field::ArrowField>::field("t");
+        assert_eq!(field.extension_type_name(), Some("arrow.variable_shape_tensor"));
+        assert_eq!(array.data_type(), field.data_type());
+        match array.data_type() {
+            DataType::Struct(fields) => {
+                assert_eq!(fields[0].name(), "data");
+                assert_eq!(fields[1].name(), "shape");
+            }
+            data_type => panic!("unexpected data type {data_type:?}"),
+        }
+
+        let round_trip: Vec<Option<ArrayD<f32>>> = array
+            .try_into_collection_as_type::<Option<VariableShapeTensor<ArrayD<f32>, 2>>>()
+            .unwrap();
+        assert_eq!(round_trip, values);
+
+        let result: Result<ArrayRef, _> =
+            vec![ArrayD::<f32>::zeros(IxDyn(&[2]))].try_into_arrow_as_type::<VariableShapeTensor<ArrayD<f32>, 2>>();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_variable_shape_tensor_invalid_shape() {
+        let DataType::Struct(fields) =
+            <VariableShapeTensor<ArrayD<f32>, 2> as arrow_convert::field::ArrowField>::data_type()
+        else {
+            unreachable!()
+        };
+        let data = ListArray::new(
+            Arc::new(Field::new("_item", DataType::Float32, false)),
+            OffsetBuffer::from_lengths([3]),
+            Arc::new(Float32Array::from(vec![1.0, 2.0, 3.0])),
+            None,
+        );
+        let shape = FixedSizeListArray::new(
+            Arc::new(Field::new("_item", DataType::Int32, false)),
+            2,
+            Arc::new(Int32Array::from(vec![2, 2])),
+            None,
+        );
+        let array: ArrayRef = Arc::new(StructArray::new(
+            fields,
+            vec![Arc::new(data), Arc::new(shape)],
+            None,
+        ));
+
+        let result: Result<Vec<ArrayD<f32>>, _> =
+            array.try_into_collection_as_type::<VariableShapeTensor<ArrayD<f32>, 2>>();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid VariableShapeTensor at index 0"), "{err}");
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for the temporal types of the `time` crate.
+#[cfg(feature = "time")]
+mod time_tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, Date32Array, Time64NanosecondArray};
+    use arrow::datatypes::{DataType, TimeUnit};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{ClampTemporal, NullTemporal};
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+    use time::macros::{date, datetime, time};
+    use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        at: OffsetDateTime,
+        local: PrimitiveDateTime,
+        day: Date,
+        time_of_day: Option<Time>,
+        elapsed: Vec<Duration>,
+    }
+
+    #[test]
+    fn test_time_roundtrip() {
+        let schema = Event::arrow_schema();
+        assert_eq!(
+            schema.field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        assert_eq!(schema.field(2).data_type(), &DataType::Date32);
+        assert_eq!(
+            schema.field(3).data_type(),
+            &DataType::Time64(TimeUnit::Nanosecond)
+        );
+
+        let original = vec![
+            Event {
+                // `OffsetDateTime` compares by instant, so the offset doesn't need to survive
+                at: datetime!(2024-03-01 12:30:00.123456789 +02:00),
+                local: datetime!(1969-12-31 23:59:59.5),
+                day: date!(1900 - 01 - 01),
+                time_of_day: Some(time!(23:59:59.999999999)),
+                elapsed: vec![Duration::nanoseconds(-5), Duration::days(3)],
+            },
+            Event {
+                at: OffsetDateTime::UNIX_EPOCH,
+                local: datetime!(2262-04-11 0:00),
+                day: date!(9999 - 12 - 31),
+                time_of_day: None,
+                elapsed: vec![],
+            },
+        ];
+
+        let array: ArrayRef = original.try_into_arrow().unwrap();
+        let round_trip: Vec<Event> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, original);
+    }
+
+    #[test]
+    fn test_time_of_day_range() {
+        let times: ArrayRef = vec![time!(0:00:01.5), time!(23:59:59.999999999)]
+            .try_into_arrow()
+            .unwrap();
+        let values = times.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(values.values().as_ref(), &[1_500_000_000, 86_399_999_999_999]);
+
+        // `Time64` admits values outside of a day, which `Time` can't represent
+        const DAY: i64 = 86_400_000_000_000;
+        for invalid in [DAY, DAY + 1, -1] {
+            let array: ArrayRef = Arc::new(Time64NanosecondArray::from(vec![Some(0), None, Some(invalid)]));
+            let result: Result<Vec<Option<Time>>, _> = array.try_into_collection();
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains("invalid time::Time at index 2"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_time_date_julian_day_range() {
+        let days: ArrayRef = vec![date!(1970 - 01 - 02), date!(1969 - 12 - 31), Date::MIN, Date::MAX]
+            .try_into_arrow()
+            .unwrap();
+        let values = days.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(&values.values()[..2], &[1, -1]);
+        let round_trip: Vec<Date> = days.try_into_collection().unwrap();
+        assert_eq!(round_trip[2..], [Date::MIN, Date::MAX]);
+
+        // days beyond year 9999 are valid `Date32` values but not valid `Date`s
+        let array: ArrayRef = Arc::new(Date32Array::from(vec![i32::MAX]));
+        let result: Result<Vec<Date>, _> = array.clone().try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid time::Date at index 0"), "{err}");
+
+        let nulls: Vec<Option<Date>> = array.try_into_collection_as_type::<NullTemporal<Date>>().unwrap();
+        assert_eq!(nulls, vec![None]);
+    }
+
+    #[test]
+    fn test_time_primitive_date_time_clamp() {
+        let values = vec![datetime!(2500-01-01 0:00), datetime!(1500-01-01 0:00)];
+        let result: Result<ArrayRef, _> = values.try_into_arrow();
+        assert!(result.is_err());
+
+        let array: ArrayRef = values
+            .try_into_arrow_as_type::<ClampTemporal<PrimitiveDateTime>>()
+            .unwrap();
+        let clamped: Vec<PrimitiveDateTime> = array.try_into_collection().unwrap();
+        assert_eq!(clamped[0], datetime!(2262-04-11 23:47:16.854775807));
+        assert_eq!(clamped[1], datetime!(1677-09-21 0:12:43.145224192));
+    }
+}
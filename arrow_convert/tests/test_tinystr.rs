@@ -47,3 +47,182 @@ fn test_tinyasciistr_max_length() {
         .expect("Failed to convert from Arrow array");
     assert_eq!(original, roundtrip);
 }
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_tinyasciistr_shorter_than_capacity() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use tinystr::TinyAsciiStr;
+
+    let original: Vec<Option<TinyAsciiStr<8>>> = vec![
+        Some(TinyAsciiStr::try_from_str("en").unwrap()),
+        None,
+        Some(TinyAsciiStr::try_from_str("").unwrap()),
+        Some(TinyAsciiStr::try_from_str("abcdefgh").unwrap()),
+    ];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Option<TinyAsciiStr<8>>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_tinyasciistr_invalid_bytes() {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, FixedSizeBinaryArray, ListArray};
+    use arrow::buffer::OffsetBuffer;
+    use arrow::datatypes::Field;
+    use arrow_convert::deserialize::{TryIntoCollection, TypedArray};
+    use tinystr::TinyAsciiStr;
+
+    let values = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+        vec![Some(b"ab".as_slice()), None, Some([0xC3, 0xA9].as_slice())].into_iter(),
+        2,
+    )
+    .unwrap();
+    let array: ArrayRef = Arc::new(values.clone());
+
+    let result: Result<Vec<Option<TinyAsciiStr<2>>>, _> = array.clone().try_into_collection();
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("invalid TinyAsciiStr<2> at index 2"), "{err}");
+    assert!(TypedArray::<Option<TinyAsciiStr<2>>>::try_new(array.clone()).is_err());
+
+    // Valid prefix still deserializes once the invalid value is sliced off.
+    let valid: Vec<Option<TinyAsciiStr<2>>> = array.slice(0, 2).try_into_collection().unwrap();
+    assert_eq!(
+        valid,
+        vec![Some(TinyAsciiStr::try_from_str("ab").unwrap()), None]
+    );
+
+    // Invalid values nested in a list are reported too.
+    let list: ArrayRef = Arc::new(ListArray::new(
+        Arc::new(Field::new("_item", values.data_type().clone(), true)),
+        OffsetBuffer::from_lengths([1, 2]),
+        Arc::new(values),
+        None,
+    ));
+    let result: Result<Vec<Vec<Option<TinyAsciiStr<2>>>>, _> = list.try_into_collection();
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("invalid TinyAsciiStr<2>"), "{err}");
+}
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_tinyasciistr_invalid_struct_field() {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, FixedSizeBinaryArray, StructArray};
+    use arrow::datatypes::Field;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::{ArrowDeserialize, ArrowField};
+    use tinystr::TinyAsciiStr;
+
+    #[derive(Debug, ArrowField, ArrowDeserialize)]
+    #[allow(dead_code)]
+    struct Locale {
+        language: TinyAsciiStr<2>,
+    }
+
+    let values =
+        FixedSizeBinaryArray::try_from_iter(vec![b"en".as_slice(), [0xFF, b'a'].as_slice()].into_iter()).unwrap();
+    let array: ArrayRef = Arc::new(StructArray::from(vec![(
+        Arc::new(Field::new("language", values.data_type().clone(), false)),
+        Arc::new(values) as ArrayRef,
+    )]));
+
+    let result: Result<Vec<Locale>, _> = array.try_into_collection();
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("Locale.language: invalid TinyAsciiStr<2> at index 1"),
+        "{err}"
+    );
+}
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_tinyasciistr_invalid_enum_variant() {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, BooleanArray, FixedSizeBinaryArray, UnionArray};
+    use arrow::buffer::ScalarBuffer;
+    use arrow::datatypes::DataType;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::ArrowField;
+    use arrow_convert::{ArrowDeserialize, ArrowField};
+    use tinystr::TinyAsciiStr;
+
+    #[derive(Debug, ArrowField, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    #[allow(dead_code)]
+    enum Code {
+        Script(TinyAsciiStr<4>),
+        Unknown,
+    }
+
+    let DataType::Union(fields, _) = <Code as ArrowField>::data_type() else {
+        unreachable!()
+    };
+    let scripts =
+        FixedSizeBinaryArray::try_from_iter(vec![b"Latn".as_slice(), [0xFF, 0xFE, 0, 0].as_slice()].into_iter())
+            .unwrap();
+    let array: ArrayRef = Arc::new(
+        UnionArray::try_new(
+            fields,
+            ScalarBuffer::from(vec![0, 1, 0]),
+            Some(ScalarBuffer::from(vec![0, 0, 1])),
+            vec![Arc::new(scripts), Arc::new(BooleanArray::from(vec![true]))],
+        )
+        .unwrap(),
+    );
+
+    let result: Result<Vec<Code>, _> = array.try_into_collection();
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("Code.Script: invalid TinyAsciiStr<4> at index 1"),
+        "{err}"
+    );
+}
+
+#[cfg(feature = "tinystr")]
+#[test]
+fn test_tinyasciistr_utf8() {
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow::datatypes::DataType;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::TinyAsciiStrUtf8;
+    use arrow_convert::serialize::TryIntoArrow;
+    use std::sync::Arc;
+    use tinystr::TinyAsciiStr;
+
+    let original: Vec<Option<TinyAsciiStr<4>>> = vec![Some(TinyAsciiStr::try_from_str("en").unwrap()), None];
+    let array: ArrayRef = original
+        .try_into_arrow_as_type::<Option<TinyAsciiStrUtf8<4>>>()
+        .unwrap();
+    assert_eq!(array.data_type(), &DataType::Utf8);
+
+    let roundtrip: Vec<Option<TinyAsciiStr<4>>> = array
+        .try_into_collection_as_type::<Option<TinyAsciiStrUtf8<4>>>()
+        .unwrap();
+    assert_eq!(original, roundtrip);
+
+    let array: ArrayRef = Arc::new(StringArray::from(vec![Some("en"), Some("toolong"), Some("é")]));
+    let err = array
+        .clone()
+        .try_into_collection_as_type::<Option<TinyAsciiStrUtf8<4>>>()
+        .map(|v: Vec<_>| v)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid TinyAsciiStr<4> at index 1"), "{err}");
+
+    let err = array
+        .slice(2, 1)
+        .try_into_collection_as_type::<Option<TinyAsciiStrUtf8<4>>>()
+        .map(|v: Vec<_>| v)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid TinyAsciiStr<4> at index 0"), "{err}");
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, LargeString, DEFAULT_FIELD_NAME};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_tuple_schema() {
+    assert_eq!(
+        <(String, f64) as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("field_0", DataType::Utf8, false),
+            Field::new("field_1", DataType::Float64, false),
+        ]))
+    );
+
+    assert_eq!(
+        <Vec<(i32, Option<bool>, LargeString)> as ArrowField>::data_type(),
+        DataType::List(Arc::new(Field::new(
+            DEFAULT_FIELD_NAME,
+            DataType::Struct(Fields::from(vec![
+                Field::new("field_0", DataType::Int32, false),
+                Field::new("field_1", DataType::Boolean, true),
+                Field::new("field_2", DataType::LargeUtf8, false),
+            ])),
+            false
+        )))
+    );
+}
+
+#[test]
+fn test_tuple_round_trip() {
+    let original = vec![("a".to_string(), 1.0), ("b".to_string(), 2.5), ("c".to_string(), -3.0)];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.len(), 3);
+    assert_eq!(struct_array.column_names(), vec!["field_0", "field_1"]);
+
+    let round_trip: Vec<(String, f64)> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_optional_round_trip() {
+    let original = vec![Some((1_i64, Some("x".to_string()))), None, Some((3, None))];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+
+    let round_trip: Vec<Option<(i64, Option<String>)>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_nested_round_trip() {
+    type Row = (u8, Vec<u16>, (bool,));
+
+    let original = vec![
+        vec![(1_u8, vec![1_u16, 2], (true,))],
+        vec![],
+        vec![(2, vec![], (false,)), (3, vec![3], (true,))],
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Vec<Row>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Quotes {
+        symbol: String,
+        levels: Vec<(String, f64)>,
+        best: Option<(f64, u32)>,
+        range: (i32, i32),
+    }
+
+    let original = vec![
+        Quotes {
+            symbol: "ABC".to_string(),
+            levels: vec![("bid".to_string(), 1.5), ("ask".to_string(), 1.6)],
+            best: Some((1.55, 10)),
+            range: (0, 10),
+        },
+        Quotes {
+            symbol: "XYZ".to_string(),
+            levels: vec![],
+            best: None,
+            range: (-5, 5),
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Quotes> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_try_finish_mismatched_fields() {
+    use arrow_convert::serialize::Tuple2Builder;
+
+    // `i32` builds `Int32` children regardless of the declared `Int64` field
+    let fields = Fields::from(vec![
+        Field::new("0", DataType::Int64, false),
+        Field::new("1", DataType::Utf8, false),
+    ]);
+    let mut builder = Tuple2Builder::<i32, String>::try_with_fields(fields.clone(), 1).unwrap();
+    builder.try_push(&(1, "a".to_string())).unwrap();
+    assert!(builder.try_finish_cloned().is_err());
+    assert!(builder.try_finish().is_err());
+
+    let err = Tuple2Builder::<i32, String>::try_with_fields(fields[..1].into(), 1).unwrap_err();
+    assert!(
+        err.to_string().contains("Tuple2Builder expects 2 struct fields, got 1"),
+        "{err}"
+    );
+
+    let mut builder = Tuple2Builder::<i32, String>::new();
+    builder.try_push(&(1, "a".to_string())).unwrap();
+    let cloned = builder.try_finish_cloned().unwrap();
+    let finished = builder.try_finish().unwrap();
+    assert_eq!(cloned.to_data(), finished.to_data());
+    assert_eq!(
+        finished.data_type(),
+        &<(i32, String) as ArrowField>::data_type()
+    );
+}
@@ -0,0 +1,132 @@
+use arrow::array::{Array, ArrayBuilder, ArrayRef};
+use arrow::buffer::Buffer;
+use arrow_convert::deserialize::TypedArray;
+use arrow_convert::field::{FixedSizeBinary, LargeString};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_typed_array_primitive() {
+    let original = vec![Some(1_i32), None, Some(3), Some(4)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let typed = TypedArray::<Option<i32>>::try_new(b).unwrap();
+
+    assert_eq!(typed.len(), 4);
+    assert!(!typed.is_empty());
+    assert!(typed.is_null(1));
+    assert_eq!(typed.get(0), Some(Some(1)));
+    assert_eq!(typed.get(1), Some(None));
+    assert_eq!(typed.get(4), None);
+    assert_eq!(typed.value(3), Some(4));
+    assert_eq!(typed.iter().collect::<Vec<_>>(), original);
+
+    let sliced = typed.slice(1, 2);
+    assert_eq!(sliced.len(), 2);
+    assert_eq!(sliced.get(1), Some(Some(3)));
+    assert_eq!(sliced.iter().collect::<Vec<_>>(), vec![None, Some(3)]);
+}
+
+#[test]
+fn test_typed_array_type_mismatch() {
+    let b: ArrayRef = vec![1_i32, 2].try_into_arrow().unwrap();
+    assert!(TypedArray::<i64>::try_new(b.clone()).is_err());
+    assert!(TypedArray::<String>::try_from(b).is_err());
+}
+
+#[test]
+fn test_typed_array_strings_and_binary() {
+    let b: ArrayRef = vec!["a".to_string(), "bc".to_string()].try_into_arrow().unwrap();
+    let typed = TypedArray::<String>::try_new(b).unwrap();
+    assert_eq!(typed.value(1), "bc");
+
+    let b: ArrayRef = vec![Some("x".to_string()), None]
+        .try_into_arrow_as_type::<Option<LargeString>>()
+        .unwrap();
+    let typed = TypedArray::<Option<LargeString>>::try_new(b).unwrap();
+    assert_eq!(typed.get(0), Some(Some("x".to_string())));
+    assert_eq!(typed.get(1), Some(None));
+
+    let b: ArrayRef = vec![Buffer::from(vec![1_u8, 2]), Buffer::from(vec![3_u8])]
+        .try_into_arrow()
+        .unwrap();
+    let typed = TypedArray::<Buffer>::try_new(b).unwrap();
+    assert_eq!(typed.value(1).as_slice(), &[3]);
+
+    let b: ArrayRef = vec![vec![1_u8, 2], vec![3, 4]]
+        .try_into_arrow_as_type::<FixedSizeBinary<2>>()
+        .unwrap();
+    let typed = TypedArray::<FixedSizeBinary<2>>::try_new(b).unwrap();
+    assert_eq!(typed.value(1), vec![3, 4]);
+}
+
+#[test]
+fn test_typed_array_lists() {
+    let original = vec![vec![1_u16, 2], vec![], vec![3]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let typed = TypedArray::<Vec<u16>>::try_new(b).unwrap();
+    assert_eq!(typed.value(2), vec![3]);
+    assert_eq!(typed.slice(1, 2).value(0), Vec::<u16>::new());
+
+    let original = vec![[1.0_f32, 2.0], [3.0, 4.0]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let typed = TypedArray::<[f32; 2]>::try_new(b).unwrap();
+    assert_eq!(typed.value(1), [3.0, 4.0]);
+}
+
+#[test]
+fn test_typed_array_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Inner {
+        a: i64,
+        b: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Outer(String, Vec<Inner>, Option<Inner>, (u8, bool));
+
+    let original = vec![
+        Some(Outer(
+            "first".to_string(),
+            vec![Inner { a: 1, b: None }],
+            Some(Inner {
+                a: 2,
+                b: Some("x".to_string()),
+            }),
+            (1, true),
+        )),
+        None,
+        Some(Outer("third".to_string(), vec![], None, (3, false))),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let typed = TypedArray::<Option<Outer>>::try_new(b).unwrap();
+
+    for (i, expected) in original.iter().enumerate() {
+        assert_eq!(typed.get(i).as_ref(), Some(expected));
+    }
+    assert_eq!(typed.slice(2, 1).value(0), original[2]);
+
+    // tuples get random access too
+    let b: ArrayRef = vec![Some((1_i32, "a".to_string())), None].try_into_arrow().unwrap();
+    let typed = TypedArray::<Option<(i32, String)>>::try_new(b).unwrap();
+    assert_eq!(typed.value(0), Some((1, "a".to_string())));
+    assert_eq!(typed.value(1), None);
+}
+
+#[test]
+fn test_typed_array_enum() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Value {
+        Int(i64),
+        Text(String),
+        Empty,
+    }
+
+    let original = vec![Value::Text("a".to_string()), Value::Int(1), Value::Empty, Value::Int(2)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let typed = TypedArray::<Value>::try_new(b).unwrap();
+    assert_eq!(typed.value(3), Value::Int(2));
+    assert_eq!(typed.value(2), Value::Empty);
+    assert_eq!(typed.value(0), Value::Text("a".to_string()));
+}
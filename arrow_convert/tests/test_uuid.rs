@@ -120,4 +120,146 @@ mod uuid {
         assert!(!field.is_nullable());
         assert_eq!(field.extension_type_name(), Some("arrow.uuid"));
     }
+
+    #[test]
+    fn test_uuid_deserialize_wrong_width() {
+        use arrow::array::FixedSizeBinaryArray;
+        use std::sync::Arc;
+
+        let array: ArrayRef = Arc::new(FixedSizeBinaryArray::try_from_iter(vec![[0u8; 8]].into_iter()).unwrap());
+        let result: Result<Vec<Uuid>, _> = array.try_into_collection();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_uuid_string_round_trip() {
+        use arrow::array::StringArray;
+        use arrow_convert::field::UuidString;
+
+        let uuids = vec![
+            Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()),
+            None,
+        ];
+        let array: ArrayRef = uuids.try_into_arrow_as_type::<Option<UuidString>>().unwrap();
+        assert_eq!(array.data_type(), &arrow::datatypes::DataType::Utf8);
+        let strings = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(strings.value(0), "550e8400-e29b-41d4-a716-446655440000");
+
+        let round_trip: Vec<Option<Uuid>> = array.try_into_collection_as_type::<Option<UuidString>>().unwrap();
+        assert_eq!(round_trip, uuids);
+    }
+
+    #[test]
+    fn test_uuid_string_invalid() {
+        use arrow::array::StringArray;
+        use arrow_convert::field::UuidString;
+        use std::sync::Arc;
+
+        let array: ArrayRef = Arc::new(StringArray::from(vec![
+            "550e8400-e29b-41d4-a716-446655440000",
+            "not-a-uuid",
+        ]));
+        let result: Result<Vec<Uuid>, _> = array.try_into_collection_as_type::<UuidString>();
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("invalid Uuid at index 1"), "{err}");
+    }
+
+    #[test]
+    fn test_uuid_string_struct_field() {
+        use arrow_convert::field::UuidString;
+        use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+        #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+        struct Row {
+            #[arrow_field(type = "UuidString")]
+            id: Uuid,
+            #[arrow_field(type = "Vec<UuidString>")]
+            related: Vec<Uuid>,
+        }
+
+        let rows = vec![Row {
+            id: Uuid::from_u128(1),
+            related: vec![Uuid::from_u128(2), Uuid::from_u128(3)],
+        }];
+        let array: ArrayRef = rows.try_into_arrow().unwrap();
+        let round_trip: Vec<Row> = array.try_into_collection().unwrap();
+        assert_eq!(round_trip, rows);
+    }
+
+    #[test]
+    fn test_uuid_binary_without_extension() {
+        use arrow::array::{FixedSizeBinaryArray, ListArray};
+        use arrow::buffer::OffsetBuffer;
+        use arrow::datatypes::Field;
+        use arrow_convert::field::UuidBinary;
+        use std::sync::Arc;
+
+        let uuid = Uuid::from_u128(42);
+        let values = FixedSizeBinaryArray::try_from_iter(vec![uuid.as_bytes()].into_iter()).unwrap();
+        let list: ArrayRef = Arc::new(ListArray::new(
+            Arc::new(Field::new("_item", values.data_type().clone(), false)),
+            OffsetBuffer::from_lengths([1]),
+            Arc::new(values),
+            None,
+        ));
+
+        // The list element lacks the extension metadata required by `Uuid`.
+        let result: Result<Vec<Vec<Uuid>>, _> = list.clone().try_into_collection();
+        assert!(result.is_err());
+
+        let round_trip: Vec<Vec<Uuid>> = list.try_into_collection_as_type::<Vec<UuidBinary>>().unwrap();
+        assert_eq!(round_trip, vec![vec![uuid]]);
+
+        let field = <UuidBinary as ArrowField>::field("id");
+        assert_eq!(field.extension_type_name(), None);
+    }
+
+    #[test]
+    fn test_uuid_string_invalid_enum_variant() {
+        use arrow::array::{StringArray, StructArray, UnionArray};
+        use arrow::buffer::ScalarBuffer;
+        use arrow::datatypes::{DataType, Field};
+        use arrow_convert::field::UuidString;
+        use arrow_convert::{ArrowDeserialize, ArrowField};
+        use std::sync::Arc;
+
+        #[derive(Debug, ArrowField, ArrowDeserialize)]
+        #[allow(dead_code)]
+        struct Tagged {
+            #[arrow_field(type = "UuidString")]
+            id: Uuid,
+        }
+
+        #[derive(Debug, ArrowField, ArrowDeserialize)]
+        #[arrow_field(type = "sparse")]
+        #[allow(dead_code)]
+        enum Entity {
+            Tagged(Tagged),
+        }
+
+        let DataType::Union(fields, _) = <Entity as ArrowField>::data_type() else {
+            unreachable!()
+        };
+        let ids: ArrayRef = Arc::new(StringArray::from(vec![
+            Uuid::from_u128(1).to_string(),
+            "nope".to_string(),
+        ]));
+        let tagged = StructArray::from(vec![(Arc::new(Field::new("id", DataType::Utf8, false)), ids)]);
+        let array: ArrayRef = Arc::new(
+            UnionArray::try_new(
+                fields,
+                ScalarBuffer::from(vec![0, 0]),
+                None,
+                vec![Arc::new(tagged)],
+            )
+            .unwrap(),
+        );
+
+        let result: Result<Vec<Entity>, _> = array.try_into_collection();
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.contains("Entity.Tagged: Tagged.id: invalid Uuid at index 1"),
+            "{err}"
+        );
+    }
 }
@@ -0,0 +1,8 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct Test<T> {
+    value: T,
+}
+
+fn main() {}
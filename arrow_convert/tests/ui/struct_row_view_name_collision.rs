@@ -0,0 +1,15 @@
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+// Requesting the default row view clashes with a borrowing struct named `RowRef`.
+#[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(row_view)]
+pub struct Row {
+    name: String,
+}
+
+#[derive(ArrowField, ArrowDeserialize)]
+pub struct RowRef<'a> {
+    name: &'a str,
+}
+
+fn main() {}
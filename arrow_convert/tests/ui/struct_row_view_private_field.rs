@@ -0,0 +1,16 @@
+mod inner {
+    use arrow_convert::{ArrowDeserialize, ArrowField};
+
+    #[derive(ArrowField, ArrowDeserialize)]
+    #[arrow_field(row_view)]
+    pub struct S {
+        pub a: i64,
+        b: i64,
+    }
+}
+
+fn read(row: inner::SRef<'_>) -> i64 {
+    row.a() + row.b()
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use arrow_convert::{ArrowField, ArrowSerialize};
+
+#[derive(ArrowField, ArrowSerialize)]
+struct Test<'a> {
+    name: &'a str,
+}
+
+fn main() {}
@@ -57,7 +57,8 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
                 syn::Type::Path(_) => &v.field_type,
                 syn::Type::Array(_) => &v.field_type,
                 syn::Type::Reference(_) => &v.field_type,
-                _ => panic!("Only `Path`, `Array`, `Reference` types are supported atm"),
+                syn::Type::Tuple(_) => &v.field_type,
+                _ => panic!("Only `Path`, `Array`, `Reference`, `Tuple` types are supported atm"),
             })
             .collect::<Vec<&syn::Type>>();
 
@@ -437,8 +438,9 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
     let array_name = &input.common.array_name();
     let iterator_name = &input.common.iterator_name();
 
-    // For unit variants, return the variant directly. For non-unit variants, get the slice of the underlying field array
-    // and deserialize to the variant type.
+    // For unit variants, return the variant directly unless the slot is null. Unit variants are always serialized
+    // as `true`, so a null in the child array can only come from a top-level null pushed into the union.
+    // For non-unit variants, get the slice of the underlying field array and deserialize to the variant type.
     let iter_next_match_block = {
         let candidates = variants.iter()
                     .zip(&variant_indices)
@@ -448,7 +450,11 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
                         if v.is_unit {
                             quote! {
                                 #lit_idx => {
-                                    Some(Some(#original_name::#name))
+                                    if arrow::array::Array::is_null(slice.deref(), 0) {
+                                        Some(None)
+                                    } else {
+                                        Some(Some(#original_name::#name))
+                                    }
                                 }
                             }
                         }
@@ -488,6 +494,18 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
                     index_iter: 0..arrow::array::Array::len(&arr),
                 }
             }
+
+            #[inline]
+            fn value_from_array_ref<'a>(b: &'a dyn arrow::array::Array, index: usize) -> <Self as arrow_convert::deserialize::ArrowArrayIterable>::Item<'a>
+            {
+                let arr = b.as_any().downcast_ref::<arrow::array::UnionArray>().unwrap();
+
+                let mut iter = #iterator_name {
+                    arr,
+                    index_iter: index..index + 1,
+                };
+                iter.next().unwrap()
+            }
         }
     };
 
@@ -530,6 +548,21 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
         }
     };
 
+    // Unit variants are stored as `true` and don't need validating.
+    let validate_children = variants
+        .iter()
+        .zip(&variant_indices)
+        .zip(&variant_types)
+        .filter(|((v, _), _)| !v.is_unit)
+        .map(|((v, lit_idx), variant_type)| {
+            let name_str = v.syn.ident.to_string();
+            quote! {
+                <#variant_type as arrow_convert::deserialize::ArrowDeserialize>::validate_array(arr.child(#lit_idx).as_ref())
+                    .map_err(|e| arrow_convert::serialize::with_field_context(e, #original_name_str, #name_str))?;
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
     let field_arrow_deserialize_impl = quote! {
         impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
             type ArrayType = #array_name;
@@ -538,6 +571,13 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
             fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
                 v
             }
+
+            fn validate_array(arr: &dyn arrow::array::Array) -> arrow::error::Result<()> {
+                if let Some(arr) = arr.as_any().downcast_ref::<arrow::array::UnionArray>() {
+                    #(#validate_children)*
+                }
+                Ok(())
+            }
         }
     };
 
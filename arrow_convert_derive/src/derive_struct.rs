@@ -79,7 +79,8 @@ impl<'a> From<&'a DeriveStruct> for Common<'a> {
                 syn::Type::Path(_) => &field.field_type,
                 syn::Type::Array(_) => &field.field_type,
                 syn::Type::Reference(_) => &field.field_type,
-                _ => panic!("Only `Path`, `Array`, `Reference` types are supported atm"),
+                syn::Type::Tuple(_) => &field.field_type,
+                _ => panic!("Only `Path`, `Array`, `Reference`, `Tuple` types are supported atm"),
             })
             .collect::<Vec<&syn::Type>>();
 
@@ -152,11 +153,14 @@ pub fn expand_field(input: DeriveStruct) -> TokenStream {
         })
         .collect::<Vec<_>>();
 
+    input.common.lifetime();
+    let (impl_generics, ty_generics, where_clause) = input.common.generics.split_for_impl();
+
     let arrow_schema_impl = if input.fields.len() == 1 && input.is_transparent {
         quote! {}
     } else {
         quote! {
-          impl #original_name {
+          impl #impl_generics #original_name #ty_generics #where_clause {
             pub fn arrow_schema() -> arrow::datatypes::Schema {
                 arrow::datatypes::Schema::new(vec![
                     #(
@@ -195,18 +199,33 @@ pub fn expand_field(input: DeriveStruct) -> TokenStream {
         }
     };
 
+    // Transparent structs keep the field-level metadata of the wrapped type, such as extension types.
+    let field_impl = if input.fields.len() == 1 && input.is_transparent {
+        let ty = &input.fields[0].field_type;
+        quote! {
+            fn field(name: &str) -> arrow::datatypes::Field {
+                <#ty as arrow_convert::field::ArrowField>::field(name)
+                    .with_nullable(<Self as arrow_convert::field::ArrowField>::is_nullable())
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     quote!(
         #arrow_schema_impl
 
-        impl arrow_convert::field::ArrowField for #original_name {
+        impl #impl_generics arrow_convert::field::ArrowField for #original_name #ty_generics #where_clause {
             type Type = Self;
 
             fn data_type() -> arrow::datatypes::DataType {
                 #data_type_impl
             }
+
+            #field_impl
         }
 
-        arrow_convert::arrow_enable_vec_for_type!(#original_name);
+        impl #impl_generics arrow_convert::field::ArrowEnableVecForType for #original_name #ty_generics #where_clause {}
     )
 }
 
@@ -216,9 +235,19 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
         visibility,
         field_members,
         field_idents,
+        field_indices,
         field_types,
+        field_names,
         ..
     } = (&input).into();
+    let original_name_str = format!("{original_name}");
+
+    if let Some(lifetime) = input.common.lifetime() {
+        abort!(
+            lifetime.span(),
+            "ArrowSerialize cannot be derived for structs with a lifetime parameter"
+        );
+    }
 
     let mutable_array_name = &input.common.mutable_array_name();
     let mutable_field_array_types = field_types
@@ -240,13 +269,60 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
     let array_impl = quote! {
         impl #mutable_array_name {
             pub fn new() -> Self {
+                Self::with_capacity(0)
+            }
+
+            pub fn with_capacity(capacity: usize) -> Self {
+                // children are created from the declared fields, so that they produce the exact field types
+                let data_type = <#original_name as arrow_convert::field::ArrowField>::data_type();
+                let arrow::datatypes::DataType::Struct(fields) = &data_type else {
+                    unreachable!("derived structs are always mapped to structs")
+                };
                 Self {
-                    #(#field_idents: <#field_types as arrow_convert::serialize::ArrowSerialize>::new_array(),)*
-                    data_type: <#original_name as arrow_convert::field::ArrowField>::data_type(),
+                    #(#field_idents: <#field_types as arrow_convert::serialize::ArrowSerialize>::new_array_for_field(&fields[#field_indices], capacity),)*
+                    data_type,
                     validity: None,
                 }
             }
 
+            pub fn try_finish(&mut self) -> arrow::error::Result<arrow::array::ArrayRef> {
+                let arrow::datatypes::DataType::Struct(fields) = self.data_type.clone() else {
+                    return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                        "{} must be mapped to a struct data type, found {}",
+                        #original_name_str,
+                        self.data_type,
+                    )));
+                };
+                let values = vec![#(
+                    <#field_types as arrow_convert::serialize::ArrowSerialize>::try_finish(&mut self.#field_idents)?,
+                )*];
+
+                Ok(std::sync::Arc::new(arrow::array::StructArray::try_new(
+                    fields,
+                    values,
+                    std::mem::take(&mut self.validity).map(|mut x| x.finish().into()),
+                )?))
+            }
+
+            pub fn try_finish_cloned(&self) -> arrow::error::Result<arrow::array::ArrayRef> {
+                let arrow::datatypes::DataType::Struct(fields) = self.data_type.clone() else {
+                    return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                        "{} must be mapped to a struct data type, found {}",
+                        #original_name_str,
+                        self.data_type,
+                    )));
+                };
+                let values = vec![#(
+                    <#mutable_field_array_types as arrow::array::ArrayBuilder>::finish_cloned(&self.#field_idents),
+                )*];
+
+                Ok(std::sync::Arc::new(arrow::array::StructArray::try_new(
+                    fields,
+                    values,
+                    self.validity.as_ref().map(|x| x.finish_cloned().into()),
+                )?))
+            }
+
             fn init_validity(&mut self) {
                 let length = <Self as arrow::array::ArrayBuilder>::len(self);
                 let mut validity = arrow::array::BooleanBufferBuilder::new(length);
@@ -275,7 +351,8 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                     Some(i) =>  {
                         let i = i.borrow() as &#original_name;
                         #(
-                            <#field_types as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(i.#field_members.borrow(), &mut self.#field_idents)?;
+                            <#field_types as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(i.#field_members.borrow(), &mut self.#field_idents)
+                                .map_err(|e| arrow_convert::serialize::with_field_context(e, #original_name_str, #field_names))?;
                         )*;
                         match &mut self.validity {
                             Some(validity) => validity.append(true),
@@ -295,31 +372,6 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 }
                 Ok(())
             }
-
-            fn align_struct_values(
-                values: Vec<arrow::array::ArrayRef>,
-                fields: &arrow::datatypes::Fields,
-            ) -> Vec<arrow::array::ArrayRef> {
-                values
-                    .into_iter()
-                    .zip(fields.iter())
-                    .map(|(value, field)| {
-                        if value.data_type() == field.data_type() {
-                            value
-                        } else {
-                            arrow::compute::cast(value.as_ref(), field.data_type()).unwrap_or_else(|e| {
-                                panic!(
-                                    "failed to cast field '{}' from {:?} to {:?}: {}",
-                                    field.name(),
-                                    value.data_type(),
-                                    field.data_type(),
-                                    e
-                                )
-                            })
-                        }
-                    })
-                    .collect()
-            }
         }
     };
 
@@ -362,41 +414,11 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             }
 
             fn finish(&mut self) -> arrow::array::ArrayRef {
-                let values = vec![#(
-                    <#mutable_field_array_types as arrow::array::ArrayBuilder>::finish(&mut self.#field_idents),
-                )*];
-
-                let arrow::datatypes::DataType::Struct(fields) =
-                  <#original_name as arrow_convert::field::ArrowField>::data_type()
-                  .clone() else {
-                    panic!("datatype is not struct")
-                  };
-                let values = #mutable_array_name::align_struct_values(values, &fields);
-
-                std::sync::Arc::new(arrow::array::StructArray::new(
-                    fields,
-                    values,
-                    std::mem::take(&mut self.validity).map(|mut x| x.finish().into()),
-                ))
+                self.try_finish().unwrap_or_else(|e| panic!("failed to finish {}: {}", #original_name_str, e))
             }
 
             fn finish_cloned(&self) -> arrow::array::ArrayRef {
-                let values = vec![#(
-                    <#mutable_field_array_types as arrow::array::ArrayBuilder>::finish_cloned(&self.#field_idents),
-                )*];
-
-                let arrow::datatypes::DataType::Struct(fields) =
-                  <#original_name as arrow_convert::field::ArrowField>::data_type()
-                  .clone() else {
-                    panic!("datatype is not struct")
-                  };
-                let values = #mutable_array_name::align_struct_values(values, &fields);
-
-                std::sync::Arc::new(arrow::array::StructArray::new(
-                    fields,
-                    values,
-                    self.validity.as_ref().map(|x| x.finish_cloned().into())
-                ))
+                self.try_finish_cloned().unwrap_or_else(|e| panic!("failed to finish {}: {}", #original_name_str, e))
             }
 
             fn as_any(&self) -> &dyn std::any::Any {
@@ -427,6 +449,21 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                     <#first_type as arrow_convert::serialize::ArrowSerialize>::new_array()
                 }
 
+                #[inline]
+                fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                    <#first_type as arrow_convert::serialize::ArrowSerialize>::new_array_with_capacity(capacity)
+                }
+
+                #[inline]
+                fn new_array_for_field(field: &arrow::datatypes::Field, capacity: usize) -> Self::ArrayBuilderType {
+                    <#first_type as arrow_convert::serialize::ArrowSerialize>::new_array_for_field(field, capacity)
+                }
+
+                #[inline]
+                fn try_finish(array: &mut Self::ArrayBuilderType) -> arrow::error::Result<arrow::array::ArrayRef> {
+                    <#first_type as arrow_convert::serialize::ArrowSerialize>::try_finish(array)
+                }
+
                 #[inline]
                 fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
                     <#first_type as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(&v.#first_field, array)
@@ -443,6 +480,16 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                     Self::ArrayBuilderType::default()
                 }
 
+                #[inline]
+                fn new_array_with_capacity(capacity: usize) -> Self::ArrayBuilderType {
+                    Self::ArrayBuilderType::with_capacity(capacity)
+                }
+
+                #[inline]
+                fn try_finish(array: &mut Self::ArrayBuilderType) -> arrow::error::Result<arrow::array::ArrayRef> {
+                    array.try_finish()
+                }
+
                 #[inline]
                 fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
                     array.try_push(Some(v))
@@ -469,25 +516,78 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
         skipped_field_names,
         field_indices,
         field_types,
+        field_names,
         ..
     } = (&input).into();
+    let original_name_str = format!("{original_name}");
 
     let array_name = &input.common.array_name();
     let iterator_name = &input.common.iterator_name();
     let is_tuple_struct = matches!(field_members[0], syn::Member::Unnamed(_));
 
+    // Structs with a lifetime parameter are deserialized by borrowing from the array. The iterator reuses
+    // the struct's lifetime so that field types can be spelled out unchanged.
+    let lifetime = input.common.lifetime().cloned();
+    let lt = lifetime
+        .clone()
+        .unwrap_or_else(|| syn::Lifetime::new("'a", proc_macro2::Span::call_site()));
+    let (_, ty_generics, _) = input.common.generics.split_for_impl();
+
+    // Fields that borrow with the struct's lifetime use `ArrowDeserializeBorrowed`, all others `ArrowDeserialize`.
+    let field_borrows = field_types
+        .iter()
+        .map(|ty| matches!(&lifetime, Some(lifetime) if contains_lifetime(quote!(#ty), lifetime)))
+        .collect::<Vec<_>>();
+    let field_deserialize_traits = field_borrows
+        .iter()
+        .map(|borrows| match borrows {
+            true => quote!(arrow_convert::deserialize::ArrowDeserializeBorrowed<#lt>),
+            false => quote!(arrow_convert::deserialize::ArrowDeserialize),
+        })
+        .collect::<Vec<_>>();
+    let field_deserialize_fns = field_borrows
+        .iter()
+        .map(|borrows| match borrows {
+            true => (
+                format_ident!("arrow_deserialize_borrowed"),
+                format_ident!("arrow_deserialize_borrowed_internal"),
+            ),
+            false => (
+                format_ident!("arrow_deserialize"),
+                format_ident!("arrow_deserialize_internal"),
+            ),
+        })
+        .collect::<Vec<_>>();
+    let field_deserialize_internal_fns = field_deserialize_fns.iter().map(|(_, f)| f).collect::<Vec<_>>();
+
     let array_decl = quote! {
         #visibility struct #array_name
         {}
     };
 
+    let struct_inst: syn::Pat = if is_tuple_struct {
+        // If the fields are unnamed, we create a tuple-struct
+        syn::parse_quote! {
+            #original_name (
+                #(<#field_types as #field_deserialize_traits>::#field_deserialize_internal_fns(#field_idents),)*
+            )
+        }
+    } else {
+        syn::parse_quote! {
+            #original_name {
+                #(#field_members: <#field_types as #field_deserialize_traits>::#field_deserialize_internal_fns(#field_idents),)*
+                #(#skipped_field_names: std::default::Default::default(),)*
+            }
+        }
+    };
+
     let array_impl = quote! {
         impl arrow_convert::deserialize::ArrowArray for #array_name
         {
             type BaseArrayType = arrow::array::StructArray;
 
             #[inline]
-            fn iter_from_array_ref<'a>(b: &'a dyn arrow::array::Array)  -> <Self as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>
+            fn iter_from_array_ref<#lt>(b: &#lt dyn arrow::array::Array)  -> <Self as arrow_convert::deserialize::ArrowArrayIterable>::Iter<#lt>
             {
                 use core::ops::Deref;
                 use arrow::array::Array;
@@ -498,20 +598,37 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
                 // for now do a straight comp
                 #iterator_name {
                     #(
-                        #field_idents: <<#field_types as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as arrow_convert::deserialize::ArrowArray>::iter_from_array_ref(values[#field_indices].deref()),
+                        #field_idents: <<#field_types as #field_deserialize_traits>::ArrayType as arrow_convert::deserialize::ArrowArray>::iter_from_array_ref(values[#field_indices].deref()),
                     )*
                     has_validity: validity.as_ref().is_some(),
                     validity_iter: validity.as_ref().map(|x| x.iter()).unwrap_or_else(|| arrow::util::bit_iterator::BitIterator::new(&[], 0, 0))
                 }
             }
+
+            #[inline]
+            fn value_from_array_ref<#lt>(b: &#lt dyn arrow::array::Array, index: usize) -> <Self as arrow_convert::deserialize::ArrowArrayIterable>::Item<#lt>
+            {
+                use core::ops::Deref;
+                use arrow::array::Array;
+
+                let arr = b.as_any().downcast_ref::<arrow::array::StructArray>().unwrap();
+                if arr.is_null(index) {
+                    return None;
+                }
+                let values = arr.columns();
+                #(
+                    let #field_idents = <<#field_types as #field_deserialize_traits>::ArrayType as arrow_convert::deserialize::ArrowArray>::value_from_array_ref(values[#field_indices].deref(), index);
+                )*
+                Some(#struct_inst)
+            }
         }
     };
 
     let array_iterable_impl = quote! {
         impl arrow_convert::deserialize::ArrowArrayIterable for #array_name
         {
-            type Item<'a> = Option<#original_name>;
-            type Iter<'a> = #iterator_name<'a>;
+            type Item<#lt> = Option<#original_name #ty_generics>;
+            type Iter<#lt> = #iterator_name<#lt>;
 
             fn iter(&self) -> Self::Iter<'_> {
                 unimplemented!("Use iter_from_array_ref");
@@ -520,35 +637,19 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
     };
 
     let iterator_decl = quote! {
-        #visibility struct #iterator_name<'a> {
+        #visibility struct #iterator_name<#lt> {
             #(
-                #field_idents: <<#field_types as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>,
+                #field_idents: <<#field_types as #field_deserialize_traits>::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Iter<#lt>,
             )*
-            validity_iter: arrow::util::bit_iterator::BitIterator<'a>,
+            validity_iter: arrow::util::bit_iterator::BitIterator<#lt>,
             has_validity: bool
         }
     };
 
-    let struct_inst: syn::Pat = if is_tuple_struct {
-        // If the fields are unnamed, we create a tuple-struct
-        syn::parse_quote! {
-            #original_name (
-                #(<#field_types as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-            )
-        }
-    } else {
-        syn::parse_quote! {
-            #original_name {
-                #(#field_members: <#field_types as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-                #(#skipped_field_names: std::default::Default::default(),)*
-            }
-        }
-    };
-
     let iterator_impl = quote! {
-        impl<'a> #iterator_name<'a> {
+        impl<#lt> #iterator_name<#lt> {
             #[inline]
-            fn return_next(&mut self) -> Option<#original_name> {
+            fn return_next(&mut self) -> Option<#original_name #ty_generics> {
                 if let (#(
                     Some(#field_idents),
                 )*) = (
@@ -566,8 +667,8 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
     };
 
     let iterator_iterator_impl = quote! {
-        impl<'a> Iterator for #iterator_name<'a> {
-            type Item = Option<#original_name>;
+        impl<#lt> Iterator for #iterator_name<#lt> {
+            type Item = Option<#original_name #ty_generics>;
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
@@ -585,6 +686,8 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
     // Special case for single-field (tuple) structs.
     if input.fields.len() == 1 && input.is_transparent {
         let first_type = &field_types[0];
+        let first_trait = &field_deserialize_traits[0];
+        let first_fn = &field_deserialize_fns[0].0;
 
         let deser_body_mapper = if is_tuple_struct {
             quote! { #original_name }
@@ -594,26 +697,78 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
         };
 
         // Everything delegates to first field.
-        quote! {
-            impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
-                type ArrayType = <#first_type as arrow_convert::deserialize::ArrowDeserialize>::ArrayType;
+        match &lifetime {
+            Some(lifetime) => quote! {
+                impl<#lifetime> arrow_convert::deserialize::ArrowDeserializeBorrowed<#lifetime> for #original_name #ty_generics {
+                    type ArrayType = <#first_type as #first_trait>::ArrayType;
+
+                    #[inline]
+                    fn arrow_deserialize_borrowed(v: <Self::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Item<#lifetime>) -> Option<Self> {
+                        <#first_type as #first_trait>::#first_fn(v).map(#deser_body_mapper)
+                    }
+                }
+            },
+            None => quote! {
+                impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+                    type ArrayType = <#first_type as arrow_convert::deserialize::ArrowDeserialize>::ArrayType;
+
+                    #[inline]
+                    fn arrow_deserialize<'a>(v: <Self::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Item<'a>) -> Option<Self> {
+                        <#first_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#deser_body_mapper)
+                    }
 
-                #[inline]
-                fn arrow_deserialize<'a>(v: <Self::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Item<'a>) -> Option<Self> {
-                    <#first_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#deser_body_mapper)
+                    #[inline]
+                    fn validate_array(arr: &dyn arrow::array::Array) -> arrow::error::Result<()> {
+                        <#first_type as arrow_convert::deserialize::ArrowDeserialize>::validate_array(arr)
+                    }
                 }
-            }
+            },
         }
     } else {
-        let field_arrow_deserialize_impl = quote! {
-            impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
-                type ArrayType = #array_name;
+        let field_arrow_deserialize_impl = match &lifetime {
+            Some(lifetime) => quote! {
+                impl<#lifetime> arrow_convert::deserialize::ArrowDeserializeBorrowed<#lifetime> for #original_name #ty_generics {
+                    type ArrayType = #array_name;
+
+                    #[inline]
+                    fn arrow_deserialize_borrowed(v: Option<Self>) -> Option<Self> {
+                        v
+                    }
+                }
+            },
+            None => quote! {
+                impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+                    type ArrayType = #array_name;
+
+                    #[inline]
+                    fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
+                        v
+                    }
 
-                #[inline]
-                fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
-                    v
+                    fn validate_array(arr: &dyn arrow::array::Array) -> arrow::error::Result<()> {
+                        if let Some(arr) = arr.as_any().downcast_ref::<arrow::array::StructArray>() {
+                            let columns = arr.columns();
+                            #(
+                                <#field_types as arrow_convert::deserialize::ArrowDeserialize>::validate_array(columns[#field_indices].as_ref())
+                                    .map_err(|e| arrow_convert::serialize::with_field_context(e, #original_name_str, #field_names))?;
+                            )*
+                        }
+                        Ok(())
+                    }
                 }
-            }
+            },
+        };
+
+        let row_view = match &input.row_view {
+            Some(ref_name) => expand_row_view(
+                &input,
+                ref_name,
+                original_name,
+                visibility,
+                &field_members,
+                &field_types,
+            ),
+            None => quote! {},
         };
 
         TokenStream::from_iter([
@@ -624,6 +779,120 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
             iterator_impl,
             iterator_iterator_impl,
             field_arrow_deserialize_impl,
+            row_view,
         ])
     }
 }
+
+/// Generates the row view requested with `#[arrow_field(row_view)]`, which deserializes individual fields on demand
+/// from the child arrays, and the `{Name}Array::row` and `{Name}Array::rows` constructors.
+fn expand_row_view(
+    input: &DeriveStruct,
+    ref_name: &syn::Ident,
+    original_name: &syn::Ident,
+    visibility: &syn::Visibility,
+    field_members: &[syn::Member],
+    field_types: &[&syn::Type],
+) -> TokenStream {
+    let array_name = &input.common.array_name();
+
+    let accessor_names = field_members
+        .iter()
+        .map(|member| match member {
+            syn::Member::Named(ident) => ident.clone(),
+            syn::Member::Unnamed(index) => format_ident!("field_{}", index),
+        })
+        .collect::<Vec<_>>();
+    let field_indices = (0..field_members.len()).map(syn::Index::from).collect::<Vec<_>>();
+    // Accessors are as visible as the fields they read.
+    let accessor_visibilities = input
+        .fields
+        .iter()
+        .filter(|field| !field.skip)
+        .map(|field| &field.syn.vis)
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[derive(Debug, Clone, Copy)]
+        #visibility struct #ref_name<'a> {
+            array: &'a arrow::array::StructArray,
+            index: usize,
+        }
+
+        impl arrow_convert::deserialize::RowView for #ref_name<'_> {
+            type Owned = #original_name;
+
+            #[inline]
+            fn row_index(&self) -> usize {
+                self.index
+            }
+
+            #[inline]
+            fn row_is_null(&self) -> bool {
+                arrow::array::Array::is_null(self.array, self.index)
+            }
+
+            #[inline]
+            fn row_to_owned(&self) -> Option<#original_name> {
+                <#array_name as arrow_convert::deserialize::ArrowArray>::value_from_array_ref(self.array, self.index)
+            }
+        }
+
+        impl<'a> #ref_name<'a> {
+            #(
+                #[inline]
+                #accessor_visibilities fn #accessor_names(&self) -> <#field_types as arrow_convert::field::ArrowField>::Type {
+                    <#field_types as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(
+                        <<#field_types as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as arrow_convert::deserialize::ArrowArray>::value_from_array_ref(
+                            self.array.column(#field_indices).as_ref(),
+                            self.index,
+                        ),
+                    )
+                }
+            )*
+        }
+
+        impl #array_name {
+            /// Returns a view of row `index` of `array`, after checking the data type and values of `array` like
+            /// [`TypedArray::try_new`](arrow_convert::deserialize::TypedArray::try_new).
+            pub fn row(array: &arrow::array::StructArray, index: usize) -> arrow::error::Result<#ref_name<'_>> {
+                arrow_convert::deserialize::check_array::<#original_name>(array)?;
+                if index >= arrow::array::Array::len(array) {
+                    return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                        "row index {} out of bounds for array of length {}",
+                        index,
+                        arrow::array::Array::len(array)
+                    )));
+                }
+                Ok(#ref_name { array, index })
+            }
+
+            /// Returns views of all rows of `array`, after checking the data type and values of `array` like
+            /// [`TypedArray::try_new`](arrow_convert::deserialize::TypedArray::try_new).
+            pub fn rows(
+                array: &arrow::array::StructArray,
+            ) -> arrow::error::Result<impl Iterator<Item = #ref_name<'_>> + '_> {
+                arrow_convert::deserialize::check_array::<#original_name>(array)?;
+                Ok((0..arrow::array::Array::len(array)).map(move |index| #ref_name { array, index }))
+            }
+        }
+    }
+}
+
+/// Whether `tokens` mention `lifetime`, e.g. `&'a str` or `Option<Inner<'a>>` for `'a`.
+fn contains_lifetime(tokens: TokenStream, lifetime: &syn::Lifetime) -> bool {
+    let mut after_quote = false;
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == '\'' => {
+                after_quote = true;
+                continue;
+            }
+            proc_macro2::TokenTree::Ident(ident) if after_quote && ident == lifetime.ident => return true,
+            proc_macro2::TokenTree::Group(group) if contains_lifetime(group.stream(), lifetime) => return true,
+            _ => {}
+        }
+        after_quote = false;
+    }
+    false
+}
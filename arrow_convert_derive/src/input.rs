@@ -3,7 +3,7 @@ use proc_macro_error2::abort;
 use quote::format_ident;
 
 use syn::spanned::Spanned;
-use syn::{DeriveInput, Ident, Lit, Visibility};
+use syn::{DeriveInput, GenericParam, Generics, Ident, Lit, Visibility};
 
 use crate::case::RenameRule;
 
@@ -19,12 +19,15 @@ pub const UNION_TYPE: &str = "type";
 pub const UNION_TYPE_SPARSE: &str = "sparse";
 pub const UNION_TYPE_DENSE: &str = "dense";
 pub const TRANSPARENT: &str = "transparent";
+pub const ROW_VIEW: &str = "row_view";
 
 pub struct DeriveCommon {
     /// The input name
     pub name: Ident,
     /// The overall visibility
     pub visibility: Visibility,
+    /// The generic parameters of the input
+    pub generics: Generics,
 }
 
 pub struct DeriveStruct {
@@ -38,6 +41,8 @@ pub struct DeriveStruct {
     pub list_element_name: Option<String>,
     /// Container-level list element metadata default.
     pub list_element_metadata: Vec<(String, String)>,
+    /// Name of the row view to generate, if requested.
+    pub row_view: Option<Ident>,
 }
 
 pub struct DeriveEnum {
@@ -59,6 +64,8 @@ pub struct ContainerAttrs {
     pub list_element_name: Option<String>,
     /// Container-level list element metadata default.
     pub list_element_metadata: Vec<(String, String)>,
+    /// Row view request, with the name of the view if one was given.
+    pub row_view: Option<(Span, Option<String>)>,
 }
 
 /// All field attributes
@@ -92,9 +99,30 @@ impl DeriveCommon {
         DeriveCommon {
             name: input.ident.clone(),
             visibility: input.vis.clone(),
+            generics: input.generics.clone(),
         }
     }
 
+    /// The lifetime parameter of a borrowing struct, if any.
+    ///
+    /// Only a single lifetime parameter is supported, type and const generics are rejected.
+    pub fn lifetime(&self) -> Option<&syn::Lifetime> {
+        let mut lifetime = None;
+        for param in &self.generics.params {
+            match param {
+                GenericParam::Lifetime(def) if lifetime.is_none() => lifetime = Some(&def.lifetime),
+                GenericParam::Lifetime(def) => {
+                    abort!(def.span(), "Only a single lifetime parameter is supported")
+                }
+                _ => abort!(
+                    param.span(),
+                    "Type and const generic parameters are not supported"
+                ),
+            }
+        }
+        lifetime
+    }
+
     pub fn mutable_array_name(&self) -> Ident {
         Ident::new(&format!("Mutable{}Array", self.name), Span::call_site())
     }
@@ -106,6 +134,10 @@ impl DeriveCommon {
     pub fn iterator_name(&self) -> Ident {
         Ident::new(&format!("{}ArrayIterator", self.name), Span::call_site())
     }
+
+    pub fn ref_name(&self) -> Ident {
+        Ident::new(&format!("{}Ref", self.name), Span::call_site())
+    }
 }
 
 impl ContainerAttrs {
@@ -115,6 +147,7 @@ impl ContainerAttrs {
         let mut rename_all: Option<RenameRule> = None;
         let mut list_element_name: Option<String> = None;
         let mut list_element_metadata: Vec<(String, String)> = Vec::new();
+        let mut row_view: Option<(Span, Option<String>)> = None;
 
         for attr in attrs {
             if attr.path().is_ident(ARROW_FIELD) {
@@ -155,6 +188,18 @@ impl ContainerAttrs {
                         };
                         list_element_name = Some(string.value());
                         Ok(())
+                    } else if nested.path.is_ident(ROW_VIEW) {
+                        let name = if nested.input.peek(syn::Token![=]) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for row_view"));
+                            };
+                            Some(string.value())
+                        } else {
+                            None
+                        };
+                        row_view = Some((nested.path.span(), name));
+                        Ok(())
                     } else if nested.path.is_ident(FIELD_LIST_ELEMENT_METADATA) {
                         nested.parse_nested_meta(|entry| {
                             let key = metadata_key(&entry.path)?;
@@ -180,6 +225,7 @@ impl ContainerAttrs {
             rename_all,
             list_element_name,
             list_element_metadata,
+            row_view,
         }
     }
 }
@@ -274,6 +320,20 @@ impl DeriveStruct {
             false
         };
 
+        let row_view = container_attrs.row_view.map(|(span, name)| {
+            if is_transparent {
+                abort!(span, "'row_view' is not supported on transparent structs");
+            }
+            if common.lifetime().is_some() {
+                abort!(span, "'row_view' is not supported on borrowing structs");
+            }
+            match name {
+                Some(name) => syn::parse_str::<Ident>(&name)
+                    .unwrap_or_else(|_| abort!(span, "Expected an identifier for row_view, found \"{}\"", name)),
+                None => common.ref_name(),
+            }
+        });
+
         DeriveStruct {
             common,
             fields: ast.fields.iter().map(DeriveField::from_ast).collect::<Vec<_>>(),
@@ -281,6 +341,7 @@ impl DeriveStruct {
             rename_all: container_attrs.rename_all,
             list_element_name: container_attrs.list_element_name,
             list_element_metadata: container_attrs.list_element_metadata,
+            row_view,
         }
     }
 }
@@ -289,6 +350,9 @@ impl DeriveEnum {
     pub fn from_ast(input: &DeriveInput, ast: &syn::DataEnum) -> DeriveEnum {
         let container_attrs = ContainerAttrs::from_ast(&input.attrs);
         let common = DeriveCommon::from_ast(input, &container_attrs);
+        if let Some((span, _)) = container_attrs.row_view {
+            abort!(span, "'row_view' is only supported on structs");
+        }
 
         DeriveEnum {
             common,